@@ -1,30 +1,320 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Months, TimeDelta, Utc};
 
-use crate::{iso8601, merge, open_graph_nodes_opt, open_graph_nodes_vec, Node};
+use crate::{
+    error::Error, iso8601, json_string, merge, open_graph_nodes_opt, open_graph_nodes_vec,
+    profile::Profile, Node, OgNodes,
+};
+
+pub(crate) const NAMESPACE: (&str, &str) = ("article", "https://ogp.me/ns/article#");
 
 #[derive(Debug, Clone, Default)]
 pub struct Article {
-    /// When the article was first published.
-    pub published_time: Option<DateTime<Utc>>,
+    /// When the article was first published. Emitted in UTC, or with its own offset, e.g. `+09:00`, with `RenderOptions::keep_offsets`.
+    pub published_time: Option<DateTime<FixedOffset>>,
 
     /// When the article was last changed.
-    pub modified_time: Option<DateTime<Utc>>,
+    pub modified_time: Option<DateTime<FixedOffset>>,
 
     ///  When the article is out of date after.
-    pub expiration_time: Option<DateTime<Utc>>,
+    pub expiration_time: Option<DateTime<FixedOffset>>,
 
     /// Writers of the article.
-    pub author: Vec<String>,
+    pub author: Vec<Author>,
 
     /// A high-level section name. E.g. Technology
     pub section: Option<String>,
 
     /// Tag words associated with this article.
-    pub tag: Vec<String>,
+    pub tag: Vec<Tag>,
+
+    /// The estimated reading time, emitted as the non-standard `article:reading_time`.
+    pub reading_time_minutes: Option<u32>,
+}
+
+/// A writer of an article, emitted as the URL of their profile.
+#[derive(Debug, Clone)]
+pub enum Author {
+    Url(String),
+
+    /// The profile found at `url`, e.g. by [`Article::resolve_author_profiles`].
+    Profile {
+        url: String,
+        profile: Profile,
+    },
+}
+
+impl Author {
+    pub fn url(&self) -> &str {
+        match self {
+            Author::Url(url) | Author::Profile { url, .. } => url,
+        }
+    }
+
+    pub(crate) fn url_mut(&mut self) -> &mut String {
+        match self {
+            Author::Url(url) | Author::Profile { url, .. } => url,
+        }
+    }
+
+    pub fn profile(&self) -> Option<&Profile> {
+        match self {
+            Author::Url(_) => None,
+            Author::Profile { profile, .. } => Some(profile),
+        }
+    }
+}
+
+impl From<String> for Author {
+    fn from(url: String) -> Self {
+        Author::Url(url)
+    }
+}
+
+impl From<&str> for Author {
+    fn from(url: &str) -> Self {
+        url.to_owned().into()
+    }
+}
+
+/// A tag word, optionally with the url of the page listing its articles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub name: String,
+
+    /// Emitted as `<link rel="tag">` with `RenderOptions::emit_tag_links`.
+    pub url: Option<String>,
+}
+
+impl Tag {
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: Some(url.into()),
+        }
+    }
+}
+
+impl From<String> for Tag {
+    fn from(name: String) -> Self {
+        Self { name, url: None }
+    }
+}
+
+impl From<&str> for Tag {
+    fn from(name: &str) -> Self {
+        name.to_owned().into()
+    }
 }
 
 impl Article {
-    pub(crate) fn to_nodes(&self) -> Vec<Node> {
+    pub fn with_times(
+        published_time: DateTime<FixedOffset>,
+        modified_time: DateTime<FixedOffset>,
+    ) -> Article {
+        Article {
+            published_time: Some(published_time),
+            modified_time: Some(modified_time),
+            ..Default::default()
+        }
+    }
+
+    /// Sets `expiration_time` to `published_time` plus `duration`. Does nothing if `published_time` is not set, or if
+    /// the sum is out of range.
+    pub fn expire_after(&mut self, duration: chrono::Duration) {
+        if let Some(expiration_time) = self
+            .published_time
+            .and_then(|published_time| published_time.checked_add_signed(duration))
+        {
+            self.expiration_time = Some(expiration_time);
+        }
+    }
+
+    /// Sets `published_time` from a time relative to now, e.g. `now`, `yesterday` or `2 days ago`.
+    ///
+    /// The units are seconds, minutes, hours, days, weeks, months and years.
+    pub fn set_published_relative(&mut self, s: &str) -> Result<(), Error> {
+        let now = Utc::now();
+        let invalid = || Error::InvalidRelativeTime(s.to_owned());
+
+        let published_time =
+            match s.trim().to_ascii_lowercase().as_str() {
+                "now" | "just now" | "today" => now,
+                "yesterday" => now - chrono::Duration::days(1),
+                x => {
+                    let x = x.strip_suffix(" ago").ok_or_else(invalid)?;
+                    let (n, unit) = x.split_once(' ').ok_or_else(invalid)?;
+                    let n: u32 = match n {
+                        "a" | "an" => 1,
+                        n => n.parse().map_err(|_| invalid())?,
+                    };
+
+                    let n = i64::from(n);
+
+                    let published_time =
+                        match unit.trim_end_matches('s') {
+                            "second" | "sec" => TimeDelta::try_seconds(n)
+                                .and_then(|delta| now.checked_sub_signed(delta)),
+                            "minute" | "min" => TimeDelta::try_minutes(n)
+                                .and_then(|delta| now.checked_sub_signed(delta)),
+                            "hour" => TimeDelta::try_hours(n)
+                                .and_then(|delta| now.checked_sub_signed(delta)),
+                            "day" => TimeDelta::try_days(n)
+                                .and_then(|delta| now.checked_sub_signed(delta)),
+                            "week" => TimeDelta::try_weeks(n)
+                                .and_then(|delta| now.checked_sub_signed(delta)),
+                            "month" => u32::try_from(n)
+                                .ok()
+                                .and_then(|n| now.checked_sub_months(Months::new(n))),
+                            "year" => u32::try_from(n.saturating_mul(12))
+                                .ok()
+                                .and_then(|n| now.checked_sub_months(Months::new(n))),
+                            _ => return Err(invalid()),
+                        };
+
+                    published_time.ok_or_else(invalid)?
+                }
+            };
+
+        self.published_time = Some(published_time.into());
+
+        Ok(())
+    }
+
+    /// Sets `published_time` and `modified_time` from the created and modified times of the file.
+    ///
+    /// `published_time` is left unset if the platform doesn't support the created time.
+    #[cfg(feature = "fs")]
+    pub fn from_file_times(path: impl AsRef<std::path::Path>) -> std::io::Result<Article> {
+        let metadata = std::fs::metadata(path)?;
+
+        let utc = |x| DateTime::<Utc>::from(x).into();
+
+        Ok(Article {
+            published_time: metadata.created().ok().map(utc),
+            modified_time: Some(utc(metadata.modified()?)),
+            ..Default::default()
+        })
+    }
+
+    /// Fetches the page of each `Author::Url` and replaces it with the `Author::Profile` found there.
+    ///
+    /// Authors whose page isn't a profile, or can't be fetched, are left as is. Returns an [`Error::Fetch`]
+    /// for each failed author, e.g. one whose page takes more than 5 seconds to connect or 10 seconds to read.
+    #[cfg(feature = "fetch")]
+    pub fn resolve_author_profiles(&mut self) -> Vec<Error> {
+        let agent = ureq::AgentBuilder::new()
+            .timeout_connect(std::time::Duration::from_secs(5))
+            .timeout_read(std::time::Duration::from_secs(10))
+            .build();
+
+        let mut errors = Vec::new();
+
+        for author in &mut self.author {
+            let Author::Url(url) = author else {
+                continue;
+            };
+
+            let fetch_error = |err: &dyn std::error::Error| Error::Fetch(err.to_string());
+
+            let html = agent
+                .get(url)
+                .call()
+                .map_err(|err| fetch_error(&err))
+                .and_then(|response| response.into_string().map_err(|err| fetch_error(&err)));
+
+            let html = match html {
+                Ok(html) => html,
+                Err(err) => {
+                    errors.push(err);
+                    continue;
+                }
+            };
+
+            let open_graph = crate::OpenGraph::from_html(&html).open_graph;
+
+            let profile = match open_graph.kind {
+                Some(crate::OpenGraphType::Profile(profile)) => Some(profile),
+                _ => open_graph.profile,
+            };
+
+            if let Some(profile) = profile {
+                *author = Author::Profile {
+                    url: std::mem::take(url),
+                    profile,
+                };
+            }
+        }
+
+        errors
+    }
+
+    /// A schema.org `Article` as JSON-LD, for a `<script type="application/ld+json">`.
+    pub fn to_json_ld(&self, headline: &str, image: &str) -> String {
+        let Article {
+            published_time,
+            modified_time,
+            author,
+            section,
+            tag,
+            ..
+        } = self;
+
+        iso8601![published_time, modified_time];
+
+        let author = (!author.is_empty()).then(|| {
+            let authors = author
+                .iter()
+                .map(|author| {
+                    format!(
+                        r#"{{"@type":"Person","url":{}}}"#,
+                        json_string(author.url())
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            format!("[{}]", authors.join(","))
+        });
+        let keywords = (!tag.is_empty()).then(|| {
+            let tags = tag.iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>();
+
+            json_string(&tags.join(", "))
+        });
+
+        let fields = [
+            ("@context", Some(json_string("https://schema.org"))),
+            ("@type", Some(json_string("Article"))),
+            ("headline", Some(json_string(headline))),
+            ("image", Some(json_string(image))),
+            ("datePublished", published_time.as_deref().map(json_string)),
+            ("dateModified", modified_time.as_deref().map(json_string)),
+            ("author", author),
+            ("articleSection", section.as_deref().map(json_string)),
+            ("keywords", keywords),
+        ];
+
+        let fields = fields
+            .into_iter()
+            .filter_map(|(key, value)| Some(format!("{}:{}", json_string(key), value?)))
+            .collect::<Vec<_>>();
+
+        format!("{{{}}}", fields.join(","))
+    }
+
+    pub fn clear_authors(&mut self) {
+        self.author.clear();
+    }
+
+    pub fn clear_tags(&mut self) {
+        self.tag.clear();
+    }
+}
+
+impl OgNodes for Article {
+    fn to_nodes(&self) -> Vec<Node<'_>> {
+        self.to_nodes_in(None)
+    }
+
+    fn to_nodes_in(&self, timezone: Option<FixedOffset>) -> Vec<Node<'_>> {
         let Article {
             published_time,
             modified_time,
@@ -32,9 +322,15 @@ impl Article {
             author,
             section,
             tag,
+            reading_time_minutes,
         } = self;
 
-        iso8601![published_time, modified_time, expiration_time];
+        iso8601![timezone; published_time, modified_time, expiration_time];
+
+        let reading_time_minutes = reading_time_minutes.map(|x| x.to_string());
+
+        let author: Vec<&str> = author.iter().map(Author::url).collect();
+        let tag: Vec<&str> = tag.iter().map(|tag| tag.name.as_str()).collect();
 
         merge(
             open_graph_nodes_opt![
@@ -42,10 +338,177 @@ impl Article {
                 ("article:modified_time", modified_time),
                 ("article:expiration_time", expiration_time),
                 ("article:section", section),
+                ("article:reading_time", reading_time_minutes),
             ],
             open_graph_nodes_vec![("article:author", author), ("article:tag", tag)],
         )
     }
+
+    fn namespace(&self) -> Option<(&'static str, &'static str)> {
+        Some(NAMESPACE)
+    }
 }
 
 // https://github.com/niallkennedy/open-graph-protocol-examples/blob/master/article-utc.html
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_from_file_times() {
+    let path = std::env::temp_dir().join("open_graph_test_from_file_times.html");
+
+    std::fs::write(&path, "<p>why can't fly</p>").unwrap();
+
+    let article = Article::from_file_times(&path).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    let modified_time = article.modified_time.unwrap();
+
+    assert!((Utc::now() - modified_time.with_timezone(&Utc)).num_minutes() < 1);
+}
+
+#[test]
+fn test_expire_after() {
+    let published_time: DateTime<FixedOffset> = "2022-12-19T16:39:57+09:00".parse().unwrap();
+    let modified_time = "2023-03-12T11:25:33+09:00".parse().unwrap();
+
+    let mut article = Article::with_times(published_time, modified_time);
+
+    article.expire_after(chrono::Duration::days(30));
+
+    assert_eq!(
+        article.expiration_time,
+        Some(published_time + chrono::Duration::days(30))
+    );
+
+    article.expire_after(chrono::Duration::MAX);
+
+    assert_eq!(
+        article.expiration_time,
+        Some(published_time + chrono::Duration::days(30))
+    );
+}
+
+#[test]
+fn test_set_published_relative() {
+    let mut article = Article::default();
+
+    article.set_published_relative("now").unwrap();
+
+    let published_time = article.published_time.unwrap().with_timezone(&Utc);
+
+    assert!((Utc::now() - published_time).num_seconds() < 5);
+
+    article.set_published_relative("2 days ago").unwrap();
+
+    let published_time = article.published_time.unwrap().with_timezone(&Utc);
+
+    assert_eq!((Utc::now() - published_time).num_days(), 2);
+
+    assert!(matches!(
+        article.set_published_relative("someday"),
+        Err(Error::InvalidRelativeTime(_))
+    ));
+}
+
+#[test]
+fn test_set_published_relative_overflow() {
+    let mut article = Article::default();
+
+    for s in [
+        "100000000 days ago",
+        "4000000000 weeks ago",
+        "4000000000 years ago",
+    ] {
+        assert!(matches!(
+            article.set_published_relative(s),
+            Err(Error::InvalidRelativeTime(_))
+        ));
+    }
+
+    assert!(article.published_time.is_none());
+}
+
+#[test]
+fn test_to_json_ld() {
+    let Some(crate::OpenGraphType::Article(article)) = crate::fixtures::example_article().kind
+    else {
+        panic!("expected article");
+    };
+
+    let json = article.to_json_ld("why can't fly", "https://og.example.com/chicken.png");
+
+    println!("{json}");
+
+    assert!(json.contains(r#""@type":"Article""#));
+    assert!(json.contains(r#""datePublished":"2022-12-19T16:39:57+09:00""#));
+    assert!(
+        json.contains(r#""author":[{"@type":"Person","url":"https://og.example.com/@syrflover"}]"#)
+    );
+    assert!(json.contains(r#""keywords":"chicken, food, fry""#));
+}
+
+#[cfg(feature = "fetch")]
+#[test]
+fn test_resolve_author_profiles() {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/@syrflover", listener.local_addr().unwrap());
+
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut request = [0; 1024];
+        let _ = stream.read(&mut request).unwrap();
+
+        let body = r#"<html><head><meta property="og:type" content="profile"/><meta property="profile:first_name" content="TaeWoo"/><meta property="profile:last_name" content="Lee"/></head></html>"#;
+
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+    });
+
+    // nothing listens on the discard port
+    let mut article = Article {
+        author: vec!["http://127.0.0.1:9/@chicken".into(), url.as_str().into()],
+        ..Default::default()
+    };
+
+    let errors = article.resolve_author_profiles();
+
+    server.join().unwrap();
+
+    assert!(matches!(errors.as_slice(), [Error::Fetch(_)]));
+    assert!(article.author[0].profile().is_none());
+
+    let author = &article.author[1];
+
+    assert_eq!(author.url(), url);
+    assert_eq!(
+        author.profile().unwrap().first_name.as_deref(),
+        Some("TaeWoo")
+    );
+}
+
+#[test]
+fn test_reading_time_minutes() {
+    let og = crate::OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        kind: crate::OpenGraphType::Article(Article {
+            reading_time_minutes: Some(5),
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="article:reading_time" content="5"/>"#));
+}
@@ -0,0 +1,46 @@
+use crate::{open_graph_nodes_opt, Node};
+
+#[derive(Debug, Clone, Default)]
+pub struct Audio {
+    /// A URL to an audio file to accompany this object.
+    pub url: String,
+
+    /// An alternate url to use if the webpage requires HTTPS.
+    pub secure_url: Option<String>,
+
+    /// A MIME type for this audio.
+    pub mime_type: Option<String>,
+}
+
+impl Audio {
+    pub(crate) fn to_nodes(&self) -> Vec<Node<'_>> {
+        let Audio {
+            url,
+            secure_url,
+            mime_type,
+        } = self;
+
+        let url = Some(url);
+
+        open_graph_nodes_opt![
+            ("og:audio", url),
+            ("og:audio:secure_url", secure_url),
+            ("og:audio:type", mime_type),
+        ]
+    }
+}
+
+impl From<String> for Audio {
+    fn from(url: String) -> Self {
+        Self {
+            url,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for Audio {
+    fn from(url: &str) -> Self {
+        url.to_owned().into()
+    }
+}
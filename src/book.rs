@@ -0,0 +1,65 @@
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::{iso8601, merge, open_graph_nodes_opt, open_graph_nodes_vec, Node, OgNodes};
+
+pub(crate) const NAMESPACE: (&str, &str) = ("book", "https://ogp.me/ns/book#");
+
+#[derive(Debug, Clone, Default)]
+pub struct Book {
+    /// Who wrote this book.
+    pub author: Vec<String>,
+
+    /// The ISBN.
+    pub isbn: Option<String>,
+
+    /// The date the book was released.
+    pub release_date: Option<DateTime<Utc>>,
+
+    /// Tag words associated with this book.
+    pub tag: Vec<String>,
+}
+
+impl OgNodes for Book {
+    fn to_nodes(&self) -> Vec<Node<'_>> {
+        self.to_nodes_in(None)
+    }
+
+    fn to_nodes_in(&self, timezone: Option<FixedOffset>) -> Vec<Node<'_>> {
+        let Book {
+            author,
+            isbn,
+            release_date,
+            tag,
+        } = self;
+
+        iso8601![timezone; release_date];
+
+        merge(
+            open_graph_nodes_vec![("book:author", author)],
+            merge(
+                open_graph_nodes_opt![("book:isbn", isbn), ("book:release_date", release_date)],
+                open_graph_nodes_vec![("book:tag", tag)],
+            ),
+        )
+    }
+
+    fn namespace(&self) -> Option<(&'static str, &'static str)> {
+        Some(NAMESPACE)
+    }
+}
+
+/// Whether `isbn` has the shape of an ISBN-10 or ISBN-13, ignoring hyphens and spaces.
+pub(crate) fn is_isbn(isbn: &str) -> bool {
+    let isbn = isbn
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .collect::<Vec<_>>();
+
+    match isbn.as_slice() {
+        [digits @ .., last] if isbn.len() == 10 => {
+            digits.iter().all(char::is_ascii_digit) && (last.is_ascii_digit() || *last == 'X')
+        }
+        digits if isbn.len() == 13 => digits.iter().all(char::is_ascii_digit),
+        _ => false,
+    }
+}
@@ -0,0 +1,63 @@
+/// Lowercased substrings of the user agents of link preview bots and search engine crawlers, from
+/// https://github.com/monperrus/crawler-user-agents/blob/master/crawler-user-agents.json
+const CRAWLERS: &[&str] = &[
+    "facebookexternalhit",
+    "facebookcatalog",
+    "meta-externalagent",
+    "twitterbot",
+    "linkedinbot",
+    "slackbot",
+    "slack-imgproxy",
+    "discordbot",
+    "telegrambot",
+    "whatsapp",
+    "skypeuripreview",
+    "pinterestbot",
+    "redditbot",
+    "embedly",
+    "quora link preview",
+    "vkshare",
+    "tumblr",
+    "bitlybot",
+    "iframely",
+    "applebot",
+    "googlebot",
+    "google-inspectiontool",
+    "bingbot",
+    "bingpreview",
+    "msnbot",
+    "yandex",
+    "baiduspider",
+    "duckduckbot",
+    "slurp",
+    "sogou",
+    "exabot",
+    "petalbot",
+    "yeti",
+    "daumoa",
+    "kakaotalk-scrap",
+    "mastodon",
+    "ahrefsbot",
+    "semrushbot",
+];
+
+/// Whether the user agent is of a known crawler, which needs the open graph tags
+/// rather than the full page.
+pub fn is_crawler(user_agent: &str) -> bool {
+    let user_agent = user_agent.to_ascii_lowercase();
+
+    CRAWLERS.iter().any(|x| user_agent.contains(x))
+}
+
+#[test]
+fn test_is_crawler() {
+    assert!(is_crawler(
+        "facebookexternalhit/1.1 (+http://www.facebook.com/externalhit_uatext.php)"
+    ));
+    assert!(is_crawler(
+        "Mozilla/5.0 (compatible; Discordbot/2.0; +https://discordapp.com)"
+    ));
+    assert!(!is_crawler(
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"
+    ));
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The given JSON value is not an object.
+    NotAnObject,
+
+    /// The given string is neither `male` nor `female`.
+    UnknownGender(String),
+
+    /// The given string is not a relative time like `2 days ago`.
+    InvalidRelativeTime(String),
+
+    /// The rendered html would exceed the given number of bytes.
+    OutputTooLarge,
+
+    /// Fetching a page failed. Only returned with the `fetch` feature.
+    Fetch(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotAnObject => write!(f, "expected a json object"),
+            Error::UnknownGender(x) => write!(f, "unknown gender: {x}"),
+            Error::InvalidRelativeTime(x) => write!(f, "invalid relative time: {x}"),
+            Error::OutputTooLarge => write!(f, "output too large"),
+            Error::Fetch(x) => write!(f, "fetch failed: {x}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,170 @@
+//! The fixtures and helpers used in the tests of this crate.
+
+use crate::{
+    article::Article,
+    profile::{Gender, Profile},
+    OpenGraph, OpenGraphType,
+};
+
+pub fn example_article() -> OpenGraph {
+    OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        kind: OpenGraphType::Article(Article {
+            published_time: Some("2022-12-19T16:39:57+09:00".parse().unwrap()),
+            modified_time: Some("2023-03-12T11:25:33+09:00".parse().unwrap()),
+            expiration_time: Some("2024-05-03T00:00:00+09:00".parse().unwrap()),
+            author: vec!["https://og.example.com/@syrflover".into()],
+            section: "Nothing".to_owned().into(),
+            tag: vec!["chicken".into(), "food".into(), "fry".into()],
+            reading_time_minutes: None,
+        })
+        .into(),
+        ..Default::default()
+    }
+}
+
+pub fn example_profile() -> OpenGraph {
+    OpenGraph {
+        title: "Syrflover".to_owned().into(),
+        description: "madome developer".to_owned().into(),
+        kind: OpenGraphType::Profile(Profile {
+            first_name: "Lee".to_owned().into(),
+            last_name: "TaeWoo".to_owned().into(),
+            username: "syrflover".to_owned().into(),
+            gender: Gender::Male.into(),
+            gender_raw: None,
+        })
+        .into(),
+        ..Default::default()
+    }
+}
+
+/// Asserts that two html strings are equal, ignoring the whitespace between elements, the order of attributes and
+/// whether their values are quoted with `'` or `"`.
+#[track_caller]
+pub fn assert_og_html_eq(actual: &str, expected: &str) {
+    assert_eq!(normalize_html(actual), normalize_html(expected));
+}
+
+fn normalize_html(html: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut tokens, rest);
+            break;
+        };
+
+        push_text(&mut tokens, &rest[..start]);
+        rest = &rest[start + 1..];
+
+        let mut quote = None;
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                match (quote, c) {
+                    (None, '"' | '\'') => quote = Some(c),
+                    (Some(q), c) if q == c => quote = None,
+                    (None, '>') => return true,
+                    _ => {}
+                }
+                false
+            })
+            .map_or(rest.len(), |(i, _)| i);
+
+        tokens.push(normalize_tag(&rest[..end]));
+        rest = rest.get(end + 1..).unwrap_or_default();
+    }
+
+    tokens
+}
+
+fn push_text(tokens: &mut Vec<String>, text: &str) {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if !text.is_empty() {
+        tokens.push(text);
+    }
+}
+
+fn normalize_tag(tag: &str) -> String {
+    let (tag, self_closing) = match tag.trim_end().strip_suffix('/') {
+        Some(tag) => (tag, true),
+        None => (tag, false),
+    };
+
+    let mut parts = Vec::new();
+    let mut part = String::new();
+    let mut quote = None;
+
+    for c in tag.chars() {
+        match (quote, c) {
+            (None, c) if c.is_whitespace() => {
+                if !part.is_empty() {
+                    parts.push(std::mem::take(&mut part));
+                }
+                continue;
+            }
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            _ => {}
+        }
+        part.push(c);
+    }
+
+    if !part.is_empty() {
+        parts.push(part);
+    }
+
+    for part in parts.iter_mut() {
+        if let Some((name, value)) = part.split_once("='") {
+            let value = value.strip_suffix('\'').unwrap_or(value);
+            *part = format!(
+                r#"{name}="{}""#,
+                value.replace("&#39;", "'").replace('"', "&quot;")
+            );
+        }
+    }
+
+    if parts.len() > 1 {
+        parts[1..].sort();
+    }
+
+    let mut r = format!("<{}", parts.join(" "));
+    if self_closing {
+        r.push('/');
+    }
+    r.push('>');
+
+    r
+}
+
+#[test]
+fn test_assert_og_html_eq() {
+    assert_og_html_eq(
+        r#"<html prefix="og: https://ogp.me/ns#"><head><meta property="og:title" content="open graph"/></head></html>"#,
+        r#"<html prefix="og: https://ogp.me/ns#">
+  <head>
+    <meta content="open graph" property="og:title" />
+  </head>
+</html>"#,
+    );
+}
+
+#[test]
+fn test_assert_og_html_eq_quotes() {
+    assert_og_html_eq(
+        r#"<meta property="og:title" content='why can&#39;t "fly"'/>"#,
+        r#"<meta property="og:title" content="why can't &quot;fly&quot;"/>"#,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_assert_og_html_eq_ne() {
+    assert_og_html_eq(
+        r#"<meta property="og:title" content="open graph"/>"#,
+        r#"<meta property="og:title" content="closed graph"/>"#,
+    );
+}
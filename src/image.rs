@@ -0,0 +1,140 @@
+use crate::{open_graph_nodes_opt, Node};
+
+#[derive(Debug, Clone, Default)]
+pub struct Image {
+    /// An image URL which should represent your object within the graph.
+    pub url: String,
+
+    /// An alternate url to use if the webpage requires HTTPS.
+    pub secure_url: Option<String>,
+
+    /// A MIME type for this image.
+    pub mime_type: Option<String>,
+
+    /// The number of pixels wide.
+    pub width: Option<u32>,
+
+    /// The number of pixels high.
+    pub height: Option<u32>,
+
+    /// A description of what is in the image (not a caption).
+    pub alt: Option<String>,
+}
+
+impl Image {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    /// An image of 1200x630, the size recommended for link previews by Facebook and X.
+    pub fn social_card(url: impl Into<String>) -> Self {
+        Self::new(url).size(1200, 630)
+    }
+
+    pub fn secure_url(mut self, secure_url: impl Into<String>) -> Self {
+        self.secure_url = Some(secure_url.into());
+        self
+    }
+
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    pub fn alt(mut self, alt: impl Into<String>) -> Self {
+        self.alt = Some(alt.into());
+        self
+    }
+
+    /// Width divided by height, if both are known.
+    pub fn aspect_ratio(&self) -> Option<f32> {
+        match (self.width, self.height) {
+            (Some(width), Some(height)) if height > 0 => Some(width as f32 / height as f32),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_nodes(&self) -> Vec<Node<'_>> {
+        self.to_nodes_with_fallback(None, None, None)
+    }
+
+    /// Uses the type and the dimensions of the plain `OpenGraph::image` for those not set.
+    pub(crate) fn to_nodes_with_fallback<'a>(
+        &'a self,
+        fallback_mime_type: Option<&'a String>,
+        fallback_width: Option<u32>,
+        fallback_height: Option<u32>,
+    ) -> Vec<Node<'a>> {
+        let Image {
+            url,
+            secure_url,
+            mime_type,
+            width,
+            height,
+            alt,
+        } = self;
+
+        let url = Some(url);
+        let mime_type = mime_type.as_ref().or(fallback_mime_type);
+        let width = width.or(fallback_width).map(|x| x.to_string());
+        let height = height.or(fallback_height).map(|x| x.to_string());
+
+        open_graph_nodes_opt![
+            ("og:image", url),
+            ("og:image:secure_url", secure_url),
+            ("og:image:type", mime_type),
+            ("og:image:width", width),
+            ("og:image:height", height),
+            ("og:image:alt", alt),
+        ]
+    }
+}
+
+#[test]
+fn test_image_builder() {
+    let image = Image::new("https://og.example.com/chicken.png")
+        .alt("a fried chicken")
+        .size(1200, 630);
+
+    let nodes = image.to_nodes();
+
+    let properties = nodes
+        .iter()
+        .map(|node| (node.attr[0].1.as_ref(), node.attr[1].1.as_ref()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        properties,
+        vec![
+            ("og:image", "https://og.example.com/chicken.png"),
+            ("og:image:width", "1200"),
+            ("og:image:height", "630"),
+            ("og:image:alt", "a fried chicken"),
+        ]
+    );
+}
+
+#[test]
+fn test_social_card() {
+    let image = Image::social_card("https://og.example.com/chicken.png");
+
+    let html = image
+        .to_nodes()
+        .iter()
+        .map(|node| node.render(&Default::default()))
+        .collect::<String>();
+
+    assert_eq!(
+        html,
+        r#"<meta property="og:image" content="https://og.example.com/chicken.png"/><meta property="og:image:width" content="1200"/><meta property="og:image:height" content="630"/>"#
+    );
+}
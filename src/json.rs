@@ -0,0 +1,296 @@
+use std::cell::RefCell;
+
+use serde_json::{Map, Value};
+
+use crate::{
+    article::Article,
+    book::Book,
+    error::Error,
+    music::{Playlist, RadioStation},
+    profile::{Gender, Profile},
+    video::VideoCommon,
+    OpenGraph, OpenGraphType,
+};
+
+impl OpenGraph {
+    /// Maps a loosely-typed json object onto the fields. Unknown keys are kept in `extra`.
+    ///
+    /// A string is accepted where an array is expected and vice versa, and the keys may be
+    /// prefixed with their namespace, e.g. `og:title` or `article:tag`.
+    pub fn from_json_value(v: &Value) -> Result<OpenGraph, Error> {
+        let object = v.as_object().ok_or(Error::NotAnObject)?;
+        let fields = Fields {
+            object,
+            read: RefCell::new(Vec::new()),
+        };
+
+        let kind = match fields.string(&["type", "og:type", "kind"]).as_deref() {
+            Some("website") => OpenGraphType::Website.into(),
+            Some("article") => OpenGraphType::Article(Article {
+                published_time: fields
+                    .string(&["published_time", "article:published_time"])
+                    .and_then(|x| x.parse().ok()),
+                modified_time: fields
+                    .string(&["modified_time", "article:modified_time"])
+                    .and_then(|x| x.parse().ok()),
+                expiration_time: fields
+                    .string(&["expiration_time", "article:expiration_time"])
+                    .and_then(|x| x.parse().ok()),
+                author: fields
+                    .strings(&["author", "authors", "article:author"])
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                section: fields.string(&["section", "article:section"]),
+                reading_time_minutes: fields
+                    .string(&["reading_time_minutes", "article:reading_time"])
+                    .and_then(|x| x.parse().ok()),
+                tag: fields
+                    .strings(&["tag", "tags", "article:tag"])
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            })
+            .into(),
+            Some("profile") => {
+                let gender = fields.string(&["gender", "profile:gender"]);
+
+                OpenGraphType::Profile(Profile {
+                    first_name: fields.string(&["first_name", "profile:first_name"]),
+                    last_name: fields.string(&["last_name", "profile:last_name"]),
+                    username: fields.string(&["username", "profile:username"]),
+                    gender: gender.as_deref().and_then(|x| x.parse().ok()),
+                    gender_raw: gender.filter(|x| x.parse::<Gender>().is_err()),
+                })
+                .into()
+            }
+            Some("book") => OpenGraphType::Book(Book {
+                author: fields.strings(&["author", "authors", "book:author"]),
+                isbn: fields.string(&["isbn", "book:isbn"]),
+                release_date: fields
+                    .string(&["release_date", "book:release_date"])
+                    .and_then(|x| x.parse().ok()),
+                tag: fields.strings(&["tag", "tags", "book:tag"]),
+            })
+            .into(),
+            Some("music.playlist") => OpenGraphType::MusicPlaylist(Playlist {
+                song: fields.strings(&["song", "songs", "music:song"]),
+                creator: fields.strings(&["creator", "creators", "music:creator"]),
+            })
+            .into(),
+            Some("music.radio_station") => OpenGraphType::MusicRadioStation(RadioStation {
+                creator: fields.strings(&["creator", "creators", "music:creator"]),
+            })
+            .into(),
+            Some(x @ ("video.movie" | "video.other")) => {
+                let video = VideoCommon {
+                    actor: fields.strings(&["actor", "actors", "video:actor"]),
+                    director: fields.strings(&["director", "directors", "video:director"]),
+                    writer: fields.strings(&["writer", "writers", "video:writer"]),
+                    duration: fields
+                        .string(&["duration", "video:duration"])
+                        .and_then(|x| x.parse().ok()),
+                    release_date: fields
+                        .string(&["release_date", "video:release_date"])
+                        .and_then(|x| x.parse().ok()),
+                    tag: fields.strings(&["tag", "tags", "video:tag"]),
+                };
+
+                match x {
+                    "video.movie" => OpenGraphType::VideoMovie(video),
+                    _ => OpenGraphType::VideoOther(video),
+                }
+                .into()
+            }
+            Some(x) => OpenGraphType::Custom {
+                type_str: x.to_owned(),
+                namespace: None,
+            }
+            .into(),
+            None => None,
+        };
+
+        let og = OpenGraph {
+            title: fields.string(&["title", "og:title"]),
+            kind,
+            url: fields.string(&["url", "og:url"]),
+            image: fields.string(&["image", "og:image"]),
+            image_type: fields.string(&["image_type", "og:image:type"]),
+            audio: fields
+                .strings(&["audio", "og:audio"])
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            video: fields
+                .strings(&["video", "og:video"])
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            description: fields.string(&["description", "og:description"]),
+            determiner: fields.string(&["determiner", "og:determiner"]),
+            locale: fields.string(&["locale", "og:locale"]),
+            alternate_locale: fields.strings(&["alternate_locale", "og:locale:alternate"]),
+            site_name: fields.string(&["site_name", "og:site_name"]),
+            see_also: fields.strings(&["see_also", "og:see_also"]),
+            theme_color: fields.string(&["theme_color", "theme-color"]),
+            updated_time: fields
+                .string(&["updated_time", "og:updated_time"])
+                .and_then(|x| x.parse().ok()),
+            referrer_policy: fields.string(&["referrer_policy", "referrer"]),
+            robots: fields.string(&["robots"]),
+            viewport: fields.string(&["viewport"]),
+            base_href: fields.string(&["base_href"]),
+            author_name: fields.string(&["author_name"]),
+            ..Default::default()
+        };
+
+        Ok(OpenGraph {
+            extra: fields.unread(),
+            ..og
+        })
+    }
+}
+
+/// The object, and the keys read from it, so the others can be kept in `extra`.
+struct Fields<'a> {
+    object: &'a Map<String, Value>,
+    read: RefCell<Vec<&'a str>>,
+}
+
+impl<'a> Fields<'a> {
+    /// The value of the first present key.
+    fn get(&self, keys: &[&str]) -> Option<&'a Value> {
+        let (key, x) = keys
+            .iter()
+            .find_map(|key| self.object.get_key_value(*key))?;
+
+        self.read.borrow_mut().push(key);
+
+        Some(x)
+    }
+
+    /// The first value of the first present key.
+    fn string(&self, keys: &[&str]) -> Option<String> {
+        match self.get(keys)? {
+            Value::Array(xs) => xs.iter().find_map(scalar),
+            x => scalar(x),
+        }
+    }
+
+    /// The values of the first present key.
+    fn strings(&self, keys: &[&str]) -> Vec<String> {
+        self.get(keys).map(scalars).unwrap_or_default()
+    }
+
+    /// The values of the keys that weren't read, in the order of the object.
+    fn unread(&self) -> Vec<(String, String)> {
+        let read = self.read.borrow();
+
+        self.object
+            .iter()
+            .filter(|(key, _)| !read.contains(&key.as_str()))
+            .flat_map(|(key, x)| scalars(x).into_iter().map(|x| (key.clone(), x)))
+            .collect()
+    }
+}
+
+fn scalar(x: &Value) -> Option<String> {
+    match x {
+        Value::String(x) => Some(x.clone()),
+        Value::Number(x) => Some(x.to_string()),
+        Value::Bool(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+fn scalars(x: &Value) -> Vec<String> {
+    match x {
+        Value::Array(xs) => xs.iter().filter_map(scalar).collect(),
+        x => scalar(x).into_iter().collect(),
+    }
+}
+
+#[test]
+fn test_from_json_value() {
+    let v = serde_json::json!({
+        "title": "why can't fly",
+        "type": "article",
+        "image": "https://og.example.com/chicken.png",
+        "tags": ["chicken", "food", "fry"],
+        "rating": 5,
+    });
+
+    let og = OpenGraph::from_json_value(&v).unwrap();
+
+    assert_eq!(og.title.as_deref(), Some("why can't fly"));
+    assert_eq!(
+        og.image.as_deref(),
+        Some("https://og.example.com/chicken.png")
+    );
+
+    let Some(OpenGraphType::Article(article)) = og.kind else {
+        panic!("expected article");
+    };
+
+    assert_eq!(
+        article.tag,
+        vec!["chicken".into(), "food".into(), "fry".into()]
+    );
+
+    assert_eq!(og.extra, vec![("rating".to_owned(), "5".to_owned())]);
+
+    assert!(OpenGraph::from_json_value(&serde_json::json!(["title"])).is_err());
+}
+
+#[test]
+fn test_from_json_value_kinds() {
+    let v = serde_json::json!({
+        "title": "why can't fly",
+        "og:type": "book",
+        "book:author": "https://og.example.com/@syrflover",
+        "isbn": "978-3-16-148410-0",
+        "see_also": ["https://og.example.com/fry"],
+        "section": "Nothing",
+    });
+
+    let og = OpenGraph::from_json_value(&v).unwrap();
+
+    let Some(OpenGraphType::Book(book)) = &og.kind else {
+        panic!("expected book");
+    };
+
+    assert_eq!(book.author, vec!["https://og.example.com/@syrflover"]);
+    assert_eq!(book.isbn.as_deref(), Some("978-3-16-148410-0"));
+    assert_eq!(og.see_also, vec!["https://og.example.com/fry"]);
+    // not a property of books
+    assert_eq!(og.extra, vec![("section".to_owned(), "Nothing".to_owned())]);
+
+    let v = serde_json::json!({
+        "type": "video.movie",
+        "actors": ["https://og.example.com/@syrflover"],
+        "duration": 600,
+    });
+
+    let og = OpenGraph::from_json_value(&v).unwrap();
+
+    let Some(OpenGraphType::VideoMovie(video)) = &og.kind else {
+        panic!("expected video.movie");
+    };
+
+    assert_eq!(video.actor, vec!["https://og.example.com/@syrflover"]);
+    assert_eq!(video.duration, Some(600));
+    assert!(og.extra.is_empty());
+
+    let v = serde_json::json!({
+        "type": "music.playlist",
+        "music:song": ["https://og.example.com/songs/1", "https://og.example.com/songs/2"],
+    });
+
+    let og = OpenGraph::from_json_value(&v).unwrap();
+
+    let Some(OpenGraphType::MusicPlaylist(playlist)) = &og.kind else {
+        panic!("expected music.playlist");
+    };
+
+    assert_eq!(playlist.song.len(), 2);
+}
@@ -1,11 +1,35 @@
 pub mod article;
+pub mod audio;
+pub mod book;
+pub mod crawler;
+pub mod error;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod fixtures;
+pub mod image;
+#[cfg(feature = "serde")]
+mod json;
+pub mod mime;
+pub mod music;
+pub mod page;
+pub mod parse;
 pub mod profile;
+pub mod twitter;
+pub mod validate;
+pub mod video;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashSet, fmt, sync::Arc};
 
-use article::Article;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+use article::{Article, Author};
+use audio::Audio;
+use book::Book;
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+use error::Error;
+use image::Image;
+use music::{Playlist, RadioStation};
+use video::{Video, VideoCommon};
 
-/// https://github.com/monperrus/crawler-user-agents/blob/master/crawler-user-agents.json
 #[derive(Debug, Clone, Default)]
 pub struct OpenGraph {
     /// The title of your object as it should appear within the graph, e.g., "The Rock".
@@ -20,11 +44,26 @@ pub struct OpenGraph {
     /// An image URL which should represent your object within the graph.
     pub image: Option<String>,
 
-    /// A URL to an audio file to accompany this object.
-    pub audio: Option<String>,
+    /// A MIME type for `image`.
+    pub image_type: Option<String>,
+
+    /// The number of pixels wide of `image`.
+    pub image_width: Option<u32>,
+
+    /// The number of pixels high of `image`.
+    pub image_height: Option<u32>,
+
+    /// Structured images, emitted after `image`.
+    pub images: Vec<Image>,
 
-    /// A URL to a video file that complements this object.
-    pub video: Option<String>,
+    /// A fallback image URL, e.g. the logo of the site, used only if neither `image` nor `images` is set.
+    pub default_image: Option<String>,
+
+    /// Audio files to accompany this object.
+    pub audio: Vec<Audio>,
+
+    /// Video files that complement this object.
+    pub video: Vec<Video>,
 
     /// A one to two sentence description of your object.
     pub description: Option<String>,
@@ -39,28 +78,270 @@ pub struct OpenGraph {
     /// An array of other locales this page is available in.
     pub alternate_locale: Vec<String>,
 
+    /// URLs of related objects, e.g. the other items of a listing page, emitted as `og:see_also`.
+    pub see_also: Vec<String>,
+
+    /// The trail of `(name, url)` from the site to this object, e.g. `("Food", "https://og.example.com/food")`.
+    ///
+    /// Emitted as a JSON-LD `BreadcrumbList` with `RenderOptions::breadcrumbs_json_ld`, and not at all otherwise.
+    pub breadcrumbs: Vec<(String, String)>,
+
     /// If your object is part of a larger web site, the name which should be displayed for the overall site. e.g., "IMDb".
     pub site_name: Option<String>,
 
     /// e.g., "#4285f4"
     pub theme_color: Option<String>,
+
+    /// The theme color in dark mode. If set, both theme colors are emitted with `prefers-color-scheme` media queries.
+    pub theme_color_dark: Option<String>,
+
+    /// The time this object was last updated.
+    pub updated_time: Option<DateTime<Utc>>,
+
+    /// e.g., "no-referrer"
+    pub referrer_policy: Option<String>,
+
+    /// The crawling directives, e.g. "index, follow", emitted as `<meta name="robots">`.
+    pub robots: Option<String>,
+
+    /// The viewport of the full html document. Default is "width=device-width, initial-scale=1".
+    pub viewport: Option<String>,
+
+    /// The base url of the relative urls in the document, emitted as the first element of the head.
+    pub base_href: Option<String>,
+
+    /// The name of the author, emitted as `<meta name="author">`. Unlike `article:author`, not a URL.
+    pub author_name: Option<String>,
+
+    /// Profile properties of an object without `kind`, which then becomes a `profile`.
+    ///
+    /// Ignored if `kind` is set.
+    pub profile: Option<Profile>,
+
+    /// Additional properties, e.g. `("myns:foo", "bar")`, emitted after the properties of `kind`.
+    pub extra: Vec<(String, String)>,
+
+    /// Custom namespaces of the `extra` properties, e.g. `("myns", "https://myns.example.com/ns#")`.
+    pub namespaces: Vec<(String, String)>,
+
+    /// Additional attributes of the `<html>` element, e.g. `("class", "og")`, emitted after the namespaces.
+    pub html_attrs: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OpenGraphView {
+    /// Each element of the head.
+    pub tags: Vec<String>,
+
+    /// All elements of the head, without the `<head>` itself.
+    pub head_html: String,
+}
+
+/// The values to show in a preview card of the object.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SocialPreview {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub site_name: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    /// If `updated_time` is not set, emit `og:updated_time` from the article's `modified_time`.
+    pub sync_updated_time: bool,
+
+    /// Put each element on its own line.
+    pub pretty: bool,
+
+    /// The number of spaces per depth when `pretty` is set.
+    pub indent: usize,
+
+    /// Percent-encode the path and query of the urls, e.g. spaces and non-ascii characters, for strict crawlers.
+    pub percent_encode_urls: bool,
+
+    /// Render the times in this offset, e.g. `+09:00`. When `None`, they're rendered in UTC, see `keep_offsets`.
+    pub timezone: Option<FixedOffset>,
+
+    /// Render the times in the offset they're stored in, e.g. `+09:00` for an article, instead of UTC. Ignored when
+    /// `timezone` is set.
+    pub keep_offsets: bool,
+
+    /// Emit `breadcrumbs` as a `BreadcrumbList` in a `<script type="application/ld+json">`.
+    pub breadcrumbs_json_ld: bool,
+
+    /// Emit `a` or `an` for the determiner `auto`, see [`OpenGraph::resolve_determiner`].
+    pub resolve_determiner: bool,
+
+    /// Quote the attribute values with `'` instead of `"`, escaping `'` in them as `&#39;`.
+    pub single_quote_attrs: bool,
+
+    /// Put a newline between the elements of the head, which may compress better. Ignored with `pretty`.
+    pub newline_between_tags: bool,
+
+    /// Don't emit the viewport meta tag in the full html document.
+    pub omit_viewport: bool,
+
+    /// Emit a single `og:locale:alternate` with the comma-separated locales, instead of repeated tags.
+    pub alternate_locale_joined: bool,
+
+    /// Emit `<meta name="keywords">` with the article's section followed by its tags.
+    pub emit_keywords: bool,
+
+    /// Move the required properties `og:title`, `og:type`, `og:url` and `og:image` before the others.
+    pub required_first: bool,
+
+    /// Declare the namespaces with `xmlns:*` attributes instead of the `prefix` attribute.
+    pub rdfa_xmlns: bool,
+
+    /// Declare the `ogp.me` namespaces with `http://`, as in the examples of the spec, instead of `https://`.
+    pub http_namespaces: bool,
+
+    /// The uris to declare for the prefixes instead of the built-in ones, e.g. `("article", "https://ns.example.com/article#")`
+    /// for a vendored copy of the namespace.
+    pub namespace_overrides: Vec<(String, String)>,
+
+    /// Emit `<link rel="tag">` for each article tag with a url.
+    pub emit_tag_links: bool,
+
+    /// Emit `<link rel="image_src">` with the url of the first `og:image`, for older consumers.
+    pub emit_image_src_link: bool,
+
+    /// Add `data-managed` with this value, e.g. `og`, to each emitted meta tag, so client code can find them.
+    pub data_managed_attr: Option<String>,
+
+    /// Emit `<meta charset>` as the first element of the head, as recommended, instead of after the properties.
+    pub charset_first: bool,
+
+    /// Emit a single theme-color without media queries, which Discord uses as the accent of the embed.
+    ///
+    /// Nothing is added that isn't set: without `theme_color` or `theme_color_dark` there is no accent, and without
+    /// an image, including `default_image`, there is no `og:image`. See [`OpenGraph::validate_with_options`], which
+    /// warns if there is no image, as Discord won't show the embed.
+    pub discord_embed: bool,
+
+    /// Put `<meta charset>` first, followed by the viewport and `<base>`, as AMP pages require.
+    ///
+    /// Only the order is changed. The other required elements of AMP, e.g. the `⚡` attribute of `<html>`, the
+    /// runtime `<script>` and the boilerplate `<style>`, are left to the page.
+    pub amp: bool,
+
+    /// Emit only the properties of these families, e.g. only the twitter tags of a [`page::PageMeta`]. All if empty.
+    ///
+    /// The namespaces aren't declared without `Family::OpenGraph`.
+    pub families: HashSet<Family>,
+}
+
+impl RenderOptions {
+    pub(crate) fn emits(&self, family: Family) -> bool {
+        self.families.is_empty() || self.families.contains(&family)
+    }
+
+    /// The offset to render the times in, `None` to keep their own.
+    pub(crate) fn time_offset(&self) -> Option<FixedOffset> {
+        self.timezone
+            .or_else(|| (!self.keep_offsets).then(|| Utc.fix()))
+    }
+}
+
+/// A group of properties, see [`RenderOptions::families`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Family {
+    /// The `<meta property>` tags, e.g. `og:title` and `article:author`.
+    OpenGraph,
+
+    /// The `<meta name="twitter:*">` tags.
+    Twitter,
+}
+
+/// A type whose properties can be rendered, e.g. [`Article`]. Implement it to add your own types,
+/// see [`OpenGraphType::Extension`].
+pub trait OgNodes: fmt::Debug + Send + Sync {
+    /// The `<meta property>` tags of the properties.
+    fn to_nodes(&self) -> Vec<Node<'_>>;
+
+    /// Like `to_nodes`, with the times in `timezone`, or in the offset they're stored in when `None`.
+    fn to_nodes_in(&self, _timezone: Option<FixedOffset>) -> Vec<Node<'_>> {
+        self.to_nodes()
+    }
+
+    /// The prefix and uri declared in the html prefix, e.g. `("article", "https://ogp.me/ns/article#")`.
+    fn namespace(&self) -> Option<(&'static str, &'static str)>;
 }
 
 #[derive(Debug, Clone)]
 pub enum OpenGraphType {
+    Website,
     Article(Article),
     Profile(Profile),
+    Book(Book),
+    MusicPlaylist(Playlist),
+    MusicRadioStation(RadioStation),
+    VideoMovie(VideoCommon),
+    VideoOther(VideoCommon),
+
+    /// A type not covered by the other variants, e.g. `restaurant.restaurant`.
+    /// Its properties can be added to `OpenGraph::extra`.
+    Custom {
+        type_str: String,
+
+        /// The prefix and uri, e.g. `("restaurant", "http://ogp.me/ns/restaurant#")`.
+        namespace: Option<(String, String)>,
+    },
+
+    /// A user-defined type, which renders its own properties.
+    Extension {
+        type_str: String,
+        object: Arc<dyn OgNodes>,
+    },
+}
+
+impl OpenGraphType {
+    /// The object rendering the properties of the type, if it has any.
+    fn object(&self) -> Option<&dyn OgNodes> {
+        match self {
+            OpenGraphType::Website | OpenGraphType::Custom { .. } => None,
+            OpenGraphType::Article(article) => Some(article),
+            OpenGraphType::Profile(profile) => Some(profile),
+            OpenGraphType::Book(book) => Some(book),
+            OpenGraphType::MusicPlaylist(playlist) => Some(playlist),
+            OpenGraphType::MusicRadioStation(radio_station) => Some(radio_station),
+            OpenGraphType::VideoMovie(video) | OpenGraphType::VideoOther(video) => Some(video),
+            OpenGraphType::Extension { object, .. } => Some(object.as_ref()),
+        }
+    }
+
+    fn namespace(&self) -> Option<(&str, &str)> {
+        match self {
+            OpenGraphType::Custom { namespace, .. } => namespace
+                .as_ref()
+                .map(|(prefix, uri)| (prefix.as_str(), uri.as_str())),
+            _ => self.object()?.namespace(),
+        }
+    }
 }
 
 impl AsRef<str> for OpenGraphType {
     fn as_ref(&self) -> &str {
         match self {
+            OpenGraphType::Website => "website",
             OpenGraphType::Article(_) => "article",
             OpenGraphType::Profile(_) => "profile",
+            OpenGraphType::Book(_) => "book",
+            OpenGraphType::MusicPlaylist(_) => "music.playlist",
+            OpenGraphType::MusicRadioStation(_) => "music.radio_station",
+            OpenGraphType::VideoMovie(_) => "video.movie",
+            OpenGraphType::VideoOther(_) => "video.other",
+            OpenGraphType::Custom { type_str, .. } | OpenGraphType::Extension { type_str, .. } => {
+                type_str
+            }
         }
     }
 }
 
+/// `$x` is borrowed into the content if it is a reference, e.g. `&String`, and moved if it is owned.
 macro_rules! open_graph_nodes_opt {
     [$(($og:expr, $x:ident)$(,)?)*] => {
         {
@@ -69,7 +350,7 @@ macro_rules! open_graph_nodes_opt {
                 if let Some($x) = $x {
                     let node = Node {
                         name: "meta",
-                        attr: vec![("property", $og.into()), ("content", $x.into())],
+                        attr: vec![("property".into(), $og.into()), ("content".into(), $x.into())],
                         children: Vec::new(),
                         text: None.into(),
                     };
@@ -92,7 +373,7 @@ macro_rules! open_graph_nodes_vec {
                 for $x in $x {
                     let node = Node {
                         name: "meta",
-                        attr: vec![("property", $og.into()), ("content", $x.into())],
+                        attr: vec![("property".into(), $og.into()), ("content".into(), $x.into())],
                         children: Vec::new(),
                         text: None.into(),
                     };
@@ -107,94 +388,587 @@ macro_rules! open_graph_nodes_vec {
 pub(crate) use open_graph_nodes_vec;
 use profile::Profile;
 
+/// An `OpenGraph` with the given fields, converted to their types, e.g. `title: "x"` to `Some("x".to_owned())`.
+#[macro_export]
+macro_rules! open_graph {
+    {$($field:ident: $value:expr),* $(,)?} => {
+        $crate::OpenGraph {
+            $($field: $crate::IntoField::into_field($value),)*
+            ..::std::default::Default::default()
+        }
+    };
+}
+
+/// The conversions of [`open_graph!`].
+#[doc(hidden)]
+pub trait IntoField<T> {
+    fn into_field(self) -> T;
+}
+
+impl<T> IntoField<T> for T {
+    fn into_field(self) -> T {
+        self
+    }
+}
+
+impl<T> IntoField<Option<T>> for T {
+    fn into_field(self) -> Option<T> {
+        Some(self)
+    }
+}
+
+impl IntoField<Option<String>> for &str {
+    fn into_field(self) -> Option<String> {
+        Some(self.to_owned())
+    }
+}
+
+impl IntoField<Vec<String>> for Vec<&str> {
+    fn into_field(self) -> Vec<String> {
+        self.into_iter().map(str::to_owned).collect()
+    }
+}
+
 impl OpenGraph {
+    /// A minimal valid `website` object.
+    pub fn website(
+        title: impl Into<String>,
+        url: impl Into<String>,
+        image: impl Into<String>,
+    ) -> OpenGraph {
+        OpenGraph {
+            title: Some(title.into()),
+            kind: Some(OpenGraphType::Website),
+            url: Some(url.into()),
+            image: Some(image.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets `locale` only if it is not set, e.g. to a site-wide default.
+    pub fn with_default_locale(mut self, locale: &str) -> Self {
+        if self.locale.is_none() {
+            self.locale = Some(locale.to_owned());
+        }
+
+        self
+    }
+
+    /// Sets `default_image` if there is no image yet, neither `image`, `images` nor `default_image`. It's emitted as
+    /// `og:image` only if no other image is set at render time.
+    ///
+    /// [`OpenGraph::validate`] warns with [`validate::ValidationWarning::FallbackImage`] when it's used.
+    pub fn with_fallback_image_if_missing(mut self, url: &str) -> Self {
+        let missing = self.image.is_none()
+            && self.images.iter().all(|image| image.url.is_empty())
+            && self.default_image.is_none();
+
+        if missing {
+            self.default_image = Some(url.to_owned());
+        }
+
+        self
+    }
+
     pub fn to_html(&self) -> String {
-        self.to_node(None).to_html()
+        self.to_html_with_options(&RenderOptions::default())
+    }
+
+    pub fn to_html_with_options(&self, options: &RenderOptions) -> String {
+        self.to_node(None, options, false).render(options)
+    }
+
+    /// Like [`OpenGraph::to_html`], but fails with [`Error::OutputTooLarge`] as soon as the html exceeds `max_bytes`.
+    pub fn to_html_bounded(&self, max_bytes: usize) -> Result<String, Error> {
+        self.to_node(None, &RenderOptions::default(), false)
+            .render_bounded(&RenderOptions::default(), max_bytes)
+    }
+
+    /// The UTF-8 bytes of [`OpenGraph::to_html`], e.g. for a http body.
+    pub fn to_html_bytes(&self) -> Vec<u8> {
+        self.to_html().into_bytes()
     }
 
     pub fn to_html_with_fallback_message(&self, fallback_message: &str) -> String {
-        self.to_node(Either::Left(fallback_message).into())
-            .to_html()
+        let options = RenderOptions::default();
+
+        self.to_node(Either::Left(fallback_message).into(), &options, false)
+            .render(&options)
     }
 
     pub fn to_html_with_fallback_node(&self, fallback_node: Node) -> String {
-        self.to_node(Either::Right(fallback_node).into()).to_html()
+        let options = RenderOptions::default();
+
+        self.to_node(Either::Right(fallback_node).into(), &options, false)
+            .render(&options)
+    }
+
+    /// Like [`OpenGraph::to_html_with_fallback_message`], but wraps the message in `tag`, e.g. `noscript`, inside the `body`.
+    pub fn to_html_with_fallback_in(&self, tag: &'static str, text: &str) -> String {
+        self.to_html_with_fallback_node(Node {
+            name: tag,
+            attr: Vec::new(),
+            children: Vec::new(),
+            text: text.into(),
+        })
+    }
+
+    /// The `<meta>` and `<link>` elements of the head, for other renderers.
+    pub fn to_meta_nodes(&self) -> Vec<Node<'_>> {
+        self.head(&RenderOptions::default(), false)
+            .children
+            .into_iter()
+            .filter(|node| matches!(node.name, "meta" | "link"))
+            .collect()
+    }
+
+    /// The `<meta property>` tags in a `<template>`, so custom elements can clone them on the client.
+    pub fn to_template_html(&self) -> String {
+        let options = RenderOptions::default();
+
+        let children = self
+            .head(&options, false)
+            .children
+            .into_iter()
+            .filter(|node| node.property().is_some())
+            .collect();
+
+        Node {
+            name: "template",
+            attr: Vec::new(),
+            children,
+            text: None.into(),
+        }
+        .render(&options)
+    }
+
+    /// Renders the first item, with the urls of the others as `og:see_also`, e.g. for a listing page.
+    pub fn to_html_collection(items: &[OpenGraph]) -> String {
+        let Some((primary, rest)) = items.split_first() else {
+            return OpenGraph::default().to_html();
+        };
+
+        let mut og = primary.clone();
+
+        og.see_also
+            .extend(rest.iter().filter_map(|item| item.url.clone()));
+
+        og.to_html()
+    }
+
+    /// Renders a full html document, including the doctype and the `<title>` tag.
+    pub fn to_document(&self) -> String {
+        self.to_document_with_options(&RenderOptions::default())
+    }
+
+    pub fn to_document_with_options(&self, options: &RenderOptions) -> String {
+        let html = self.to_node(None, options, true).render(options);
+
+        if options.pretty {
+            format!("<!DOCTYPE html>\n{html}")
+        } else {
+            format!("<!DOCTYPE html>{html}")
+        }
+    }
+
+    /// [`OpenGraph::to_document`] in chunks, the doctype, the start tags, each element of the head and the end tags,
+    /// so a server can write them as they are rendered.
+    pub fn render_chunks(&self) -> impl Iterator<Item = String> + '_ {
+        let options = RenderOptions::default();
+
+        let mut html = self.to_node(None, &options, true);
+        let head = html.children.remove(0);
+
+        let start_tags = [
+            "<!DOCTYPE html>".to_owned(),
+            html.start_tag(&options),
+            head.start_tag(&options),
+        ];
+
+        start_tags
+            .into_iter()
+            .chain(
+                head.children
+                    .into_iter()
+                    .map(move |node| node.render(&options)),
+            )
+            .chain(["</head>".to_owned(), "</html>".to_owned()])
+    }
+
+    /// A full html document with the fallback message as its body, to serve to crawlers,
+    /// see [`crawler::is_crawler`], while people get the full page.
+    pub fn to_crawler_page(&self, fallback: &str) -> String {
+        let options = RenderOptions::default();
+
+        let html = self
+            .to_node(Either::Left(fallback).into(), &options, true)
+            .render(&options);
+
+        format!("<!DOCTYPE html>{html}")
+    }
+
+    /// The determiner, with `auto` resolved to `a` or `an` by the first letter of the title.
+    ///
+    /// `auto` is kept if there is no title.
+    pub fn resolve_determiner(&self) -> Option<&str> {
+        let determiner = self.determiner.as_deref()?;
+
+        if determiner != "auto" {
+            return Some(determiner);
+        }
+
+        let Some(title) = self.title.as_deref() else {
+            return Some(determiner);
+        };
+
+        let vowel = title
+            .chars()
+            .find(|c| c.is_alphanumeric())
+            .is_some_and(|c| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u'));
+
+        Some(if vowel { "an" } else { "a" })
+    }
+
+    /// Clears `image` along with its type and dimensions.
+    pub fn clear_image(&mut self) {
+        self.image = None;
+        self.image_type = None;
+        self.image_width = None;
+        self.image_height = None;
+    }
+
+    pub fn clear_images(&mut self) {
+        self.images.clear();
+    }
+
+    pub fn clear_alternate_locales(&mut self) {
+        self.alternate_locale.clear();
+    }
+
+    pub fn clear_extra(&mut self) {
+        self.extra.clear();
+    }
+
+    /// Removes the given query parameters, e.g. `utm_source`, from all urls.
+    ///
+    /// Relative urls are left as is.
+    pub fn strip_query_params(&mut self, params: &[&str]) {
+        let OpenGraph {
+            url,
+            image,
+            default_image,
+            images,
+            audio,
+            video,
+            see_also,
+            breadcrumbs,
+            kind,
+            ..
+        } = self;
+
+        // the same urls as `validate_urls`
+        let kind_urls: Vec<&mut String> = match kind {
+            Some(OpenGraphType::Article(article)) => article
+                .author
+                .iter_mut()
+                .map(Author::url_mut)
+                .chain(article.tag.iter_mut().filter_map(|tag| tag.url.as_mut()))
+                .collect(),
+            Some(OpenGraphType::Book(book)) => book.author.iter_mut().collect(),
+            Some(OpenGraphType::MusicPlaylist(playlist)) => playlist
+                .song
+                .iter_mut()
+                .chain(playlist.creator.iter_mut())
+                .collect(),
+            Some(OpenGraphType::MusicRadioStation(radio_station)) => {
+                radio_station.creator.iter_mut().collect()
+            }
+            Some(OpenGraphType::VideoMovie(video) | OpenGraphType::VideoOther(video)) => video
+                .actor
+                .iter_mut()
+                .chain(video.director.iter_mut())
+                .chain(video.writer.iter_mut())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        for x in [url, image, default_image]
+            .into_iter()
+            .flatten()
+            .chain(
+                images
+                    .iter_mut()
+                    .flat_map(|image| [Some(&mut image.url), image.secure_url.as_mut()])
+                    .flatten(),
+            )
+            .chain(
+                audio
+                    .iter_mut()
+                    .flat_map(|audio| [Some(&mut audio.url), audio.secure_url.as_mut()])
+                    .flatten(),
+            )
+            .chain(video.iter_mut().flat_map(|video| {
+                [Some(&mut video.url), video.secure_url.as_mut()]
+                    .into_iter()
+                    .flatten()
+                    .chain(video.actor.iter_mut())
+            }))
+            .chain(see_also.iter_mut())
+            .chain(breadcrumbs.iter_mut().map(|(_, url)| url))
+            .chain(kind_urls)
+        {
+            strip_query_params(x, params);
+        }
+    }
+
+    /// The elements of the head, without the `<head>` itself.
+    pub fn to_meta_tags_html(&self) -> String {
+        let options = RenderOptions::default();
+
+        self.head(&options, false)
+            .children
+            .iter()
+            .map(|node| node.render(&options))
+            .collect()
+    }
+
+    /// Pre-rendered data for template engines, e.g. `{{ og.head_html }}`.
+    pub fn to_view(&self) -> OpenGraphView {
+        let options = RenderOptions::default();
+
+        let tags = self
+            .head(&options, false)
+            .children
+            .iter()
+            .map(|node| node.render(&options))
+            .collect::<Vec<_>>();
+
+        OpenGraphView {
+            head_html: tags.concat(),
+            tags,
+        }
+    }
+
+    /// The best available values for a preview card.
+    pub fn social_preview(&self) -> SocialPreview {
+        SocialPreview {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            image: self
+                .image
+                .clone()
+                .or_else(|| {
+                    self.images
+                        .iter()
+                        .find(|image| !image.url.is_empty())
+                        .map(|image| image.url.clone())
+                })
+                .or_else(|| self.default_image.clone()),
+            site_name: self.site_name.clone(),
+            url: self.url.clone(),
+        }
+    }
+
+    /// The image of `images` whose aspect ratio is the closest to `aspect`, e.g. 1.91 for wide cards or 1.0 for square ones.
+    ///
+    /// Images without dimensions are not considered.
+    pub fn image_for(&self, aspect: f32) -> Option<&Image> {
+        self.images
+            .iter()
+            .filter(|image| !image.url.is_empty())
+            .filter_map(|image| Some((image, (image.aspect_ratio()? - aspect).abs())))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(image, _)| image)
     }
 
-    fn to_node<'a>(&'a self, fallback: Option<Either<&'a str, Node<'a>>>) -> Node<'a> {
+    /// Whether no tags would be emitted, other than `<meta charset>` and the viewport of documents.
+    pub fn is_empty(&self) -> bool {
         let OpenGraph {
             title,
             kind,
             url,
             image,
+            image_type: _,
+            image_width: _,
+            image_height: _,
+            images,
+            default_image,
             audio,
             video,
             description,
             determiner,
             locale,
             alternate_locale,
+            see_also,
+            breadcrumbs,
             site_name,
             theme_color,
+            theme_color_dark,
+            updated_time,
+            referrer_policy,
+            robots,
+            viewport: _,
+            base_href,
+            author_name,
+            profile,
+            extra,
+            namespaces: _,
+            html_attrs: _,
         } = self;
 
-        let (ns, nodes) = match kind.as_ref() {
-            Some(OpenGraphType::Article(article)) => {
-                let ns = "og: https://ogp.me/ns# article: http://ogp.me/ns/article#";
-                let nodes = article.to_nodes();
-                (ns, nodes)
+        let options = [
+            title,
+            url,
+            image,
+            default_image,
+            description,
+            determiner,
+            locale,
+            site_name,
+            theme_color,
+            theme_color_dark,
+            referrer_policy,
+            robots,
+            base_href,
+            author_name,
+        ];
+
+        options.iter().all(|x| x.is_none())
+            && kind.is_none()
+            && profile.is_none()
+            && updated_time.is_none()
+            && images.iter().all(|image| image.url.is_empty())
+            && audio.is_empty()
+            && video.is_empty()
+            && alternate_locale.is_empty()
+            && see_also.is_empty()
+            && breadcrumbs.is_empty()
+            && extra.is_empty()
+    }
+
+    /// The property/content pairs of the `<meta property>` tags, in order.
+    pub(crate) fn properties(&self) -> Vec<(String, String)> {
+        self.head(&RenderOptions::default(), false)
+            .children
+            .iter()
+            .filter_map(|node| {
+                let content = node.attr.iter().find(|(key, _)| key == "content")?;
+
+                Some((node.property()?.to_owned(), content.1.to_string()))
+            })
+            .collect()
+    }
+
+    /// The properties that changed since `previous`, for updating the meta tags of a page in place.
+    ///
+    /// A changed property is listed with all of its new contents, which replace its existing tags.
+    /// A property that was removed is listed once with `None`.
+    pub fn to_patch(&self, previous: &OpenGraph) -> Vec<(String, Option<String>)> {
+        let properties = self.properties();
+        let previous_properties = previous.properties();
+
+        let contents_of = |properties: &[(String, String)], property: &str| -> Vec<String> {
+            properties
+                .iter()
+                .filter(|(x, _)| x == property)
+                .map(|(_, content)| content.clone())
+                .collect()
+        };
+
+        let mut seen = Vec::new();
+        let mut r = Vec::new();
+
+        for (property, _) in properties.iter().chain(previous_properties.iter()) {
+            if seen.contains(&property) {
+                continue;
             }
-            Some(OpenGraphType::Profile(profile)) => {
-                let ns = "og: https://ogp.me/ns# profile: https://ogp.me/ns/profile#";
-                let nodes = profile.to_nodes();
-                (ns, nodes)
+            seen.push(property);
+
+            let contents = contents_of(&properties, property);
+
+            if contents == contents_of(&previous_properties, property) {
+                continue;
             }
-            None => ("og: https://ogp.me/ns#", Vec::new()),
-        };
 
-        let kind = as_ref(kind);
+            if contents.is_empty() {
+                r.push((property.clone(), None));
+            } else {
+                r.extend(contents.into_iter().map(|x| (property.clone(), Some(x))));
+            }
+        }
 
-        let open_graph_nodes = merge(
-            open_graph_nodes_opt![
-                ("og:title", title),
-                ("og:type", kind),
-                ("og:url", url),
-                ("og:image", image),
-                ("og:audio", audio),
-                ("og:video", video),
-                ("og:description", description),
-                ("og:determiner", determiner),
-                ("og:locale", locale),
-                ("og:site_name", site_name),
-            ],
-            open_graph_nodes_vec![("og:locale:alternate", alternate_locale)],
-        );
+        r
+    }
+
+    /// The properties inside an html comment, e.g. for inspecting in View Source.
+    pub fn to_debug_comment(&self) -> String {
+        let mut r = String::from("<!-- og:\n");
+
+        for (property, content) in self.properties() {
+            let mut line = format!("{property}: {content}\n");
+
+            // `--` can't be in a comment, and `---` is `- --` after one pass
+            while line.contains("--") {
+                line = line.replace("--", "- -");
+            }
+
+            r.push_str(&line);
+        }
+
+        r.push_str("-->");
+
+        r
+    }
+
+    /// A short hash of the metadata for the `ETag` header, the same whatever the render options.
+    pub fn etag(&self) -> String {
+        let options = RenderOptions::default();
+        let html = self.head(&options, false).render(&options);
+
+        // FNV-1a, which is stable across releases unlike `DefaultHasher`
+        let hash = html.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+        format!("\"{hash:016x}\"")
+    }
+
+    /// The `<head>` subtree, to be spliced into a larger document.
+    pub fn head_node(&self) -> Node<'_> {
+        self.head(&RenderOptions::default(), false)
+    }
 
+    fn to_node<'a>(
+        &'a self,
+        fallback: Option<Either<&'a str, Node<'a>>>,
+        options: &RenderOptions,
+        document: bool,
+    ) -> Node<'a> {
         Node {
             name: "html",
-            attr: vec![("prefix", ns.into())],
+            attr: merge(
+                if !options.emits(Family::OpenGraph) {
+                    Vec::new()
+                } else if options.rdfa_xmlns {
+                    self.namespaces()
+                        .into_iter()
+                        .map(|(prefix, uri)| {
+                            (
+                                format!("xmlns:{prefix}").into(),
+                                namespace_uri(prefix, uri, options),
+                            )
+                        })
+                        .collect()
+                } else {
+                    vec![("prefix".into(), self.prefix(options).into())]
+                },
+                self.html_attrs
+                    .iter()
+                    .map(|(name, value)| (name.as_str().into(), value.as_str().into()))
+                    .collect(),
+            ),
             children: append_opt(
-                vec![Node {
-                    name: "head",
-                    attr: Vec::new(),
-                    children: append_opt(
-                        append(
-                            merge(open_graph_nodes, nodes),
-                            Node {
-                                name: "meta",
-                                attr: vec![("charset", "utf-8".into())],
-                                children: Vec::new(),
-                                text: None.into(),
-                            },
-                        ),
-                        theme_color.as_deref().map(|color| Node {
-                            name: "meta",
-                            attr: vec![("name", "theme-color".into()), ("content", color.into())],
-                            children: Vec::new(),
-                            text: None.into(),
-                        }),
-                    ),
-                    text: None.into(),
-                }],
+                vec![self.head(options, document)],
                 fallback.map(|text_or_node| match text_or_node {
                     Either::Left(text) => Node {
                         name: "body",
@@ -213,17 +987,671 @@ impl OpenGraph {
             text: None.into(),
         }
     }
-}
 
-macro_rules! iso8601 {
-    [$($x:ident$(,)?)*] => {
-        $(
-            let $x = $x.map(|x| x.to_rfc3339());
-        )*
-    };
-}
+    /// A schema.org `BreadcrumbList` of `breadcrumbs` as JSON-LD, for a `<script type="application/ld+json">`.
+    pub fn breadcrumbs_json_ld(&self) -> Option<String> {
+        if self.breadcrumbs.is_empty() {
+            return None;
+        }
 
-pub(crate) use iso8601;
+        let items = self
+            .breadcrumbs
+            .iter()
+            .enumerate()
+            .map(|(i, (name, url))| {
+                format!(
+                    r#"{{"@type":"ListItem","position":{},"name":{},"item":{}}}"#,
+                    i + 1,
+                    json_string(name),
+                    json_string(url)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Some(format!(
+            r#"{{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":[{}]}}"#,
+            items.join(",")
+        ))
+    }
+
+    /// Registers a namespace, which is declared in the html prefix if an `extra` property uses it.
+    pub fn register_namespace(&mut self, prefix: impl Into<String>, uri: impl Into<String>) {
+        self.namespaces.push((prefix.into(), uri.into()));
+    }
+
+    /// Adds the `extra` property `{namespace}:{property}`, registering the namespace if it isn't yet.
+    pub fn add_custom(&mut self, namespace: &str, uri: &str, property: &str, content: &str) {
+        if !self
+            .namespaces
+            .iter()
+            .any(|(prefix, _)| prefix == namespace)
+        {
+            self.register_namespace(namespace, uri);
+        }
+
+        self.extra
+            .push((format!("{namespace}:{property}"), content.to_owned()));
+    }
+
+    fn namespaces(&self) -> Vec<(&str, &str)> {
+        let namespaces = append_opt(
+            vec![("og", "https://ogp.me/ns#")],
+            match (&self.kind, &self.profile) {
+                (Some(kind), _) => kind.namespace(),
+                (None, Some(_)) => Some(profile::NAMESPACE),
+                (None, None) => None,
+            },
+        );
+
+        let namespaces = if !namespaces.contains(&video::NAMESPACE)
+            && self.video.iter().any(Video::has_metadata)
+        {
+            append(namespaces, video::NAMESPACE)
+        } else {
+            namespaces
+        };
+
+        self.namespaces
+            .iter()
+            .filter(|(prefix, _)| {
+                self.extra.iter().any(|(property, _)| {
+                    property
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|x| x.starts_with(':'))
+                })
+            })
+            .fold(namespaces, |namespaces, (prefix, uri)| {
+                if namespaces.iter().any(|(x, _)| x == prefix) {
+                    namespaces
+                } else {
+                    append(namespaces, (prefix.as_str(), uri.as_str()))
+                }
+            })
+    }
+
+    fn prefix(&self, options: &RenderOptions) -> String {
+        self.namespaces()
+            .into_iter()
+            .map(|(prefix, uri)| format!("{prefix}: {}", namespace_uri(prefix, uri, options)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn head<'a>(&'a self, options: &RenderOptions, document: bool) -> Node<'a> {
+        let OpenGraph {
+            title,
+            kind,
+            url,
+            image,
+            image_type,
+            image_width,
+            image_height,
+            images,
+            default_image,
+            audio,
+            video,
+            description,
+            determiner,
+            locale,
+            alternate_locale,
+            see_also,
+            breadcrumbs: _,
+            site_name,
+            theme_color,
+            theme_color_dark,
+            updated_time,
+            referrer_policy,
+            robots,
+            viewport,
+            base_href,
+            author_name,
+            profile,
+            extra,
+            namespaces: _,
+            html_attrs: _,
+        } = self;
+
+        let nodes = match kind.as_ref() {
+            Some(kind) => kind
+                .object()
+                .map(|object| object.to_nodes_in(options.time_offset()))
+                .unwrap_or_default(),
+            None => profile.as_ref().map(Profile::to_nodes).unwrap_or_default(),
+        };
+
+        let updated_time = updated_time.or_else(|| match kind {
+            Some(OpenGraphType::Article(article)) if options.sync_updated_time => article
+                .modified_time
+                .map(|modified_time| modified_time.with_timezone(&Utc)),
+            _ => None,
+        });
+
+        iso8601![options.time_offset(); updated_time];
+
+        // the plain image is merged into its structured duplicate, which is emitted first
+        let duplicate = image
+            .as_ref()
+            .and_then(|url| images.iter().position(|image| image.url == *url));
+        let merged_image_nodes = duplicate
+            .map(|i| {
+                images[i].to_nodes_with_fallback(image_type.as_ref(), *image_width, *image_height)
+            })
+            .unwrap_or_default();
+        let image = image.as_ref().filter(|_| duplicate.is_none());
+
+        let image_type = image_type.as_ref().filter(|_| image.is_some());
+        let image_width = image_width
+            .filter(|_| image.is_some())
+            .map(|x| x.to_string());
+        let image_height = image_height
+            .filter(|_| image.is_some())
+            .map(|x| x.to_string());
+        let image = image.or(default_image
+            .as_ref()
+            .filter(|_| images.iter().all(|image| image.url.is_empty())));
+
+        let image_src_node = image
+            .or(duplicate.map(|i| &images[i].url))
+            .or(images
+                .iter()
+                .map(|image| &image.url)
+                .find(|url| !url.is_empty()))
+            .filter(|_| options.emit_image_src_link)
+            .map(|url| Node {
+                name: "link",
+                attr: vec![
+                    ("rel".into(), "image_src".into()),
+                    (
+                        "href".into(),
+                        if options.percent_encode_urls {
+                            percent_encode_url(url).into()
+                        } else {
+                            url.as_str().into()
+                        },
+                    ),
+                ],
+                children: Vec::new(),
+                text: None.into(),
+            });
+
+        let determiner = if options.resolve_determiner {
+            self.resolve_determiner()
+        } else {
+            determiner.as_deref()
+        };
+
+        let alternate_locale: Vec<Cow<str>> =
+            if options.alternate_locale_joined && !alternate_locale.is_empty() {
+                vec![alternate_locale.join(",").into()]
+            } else {
+                alternate_locale.iter().map(|x| x.as_str().into()).collect()
+            };
+
+        let viewport = viewport
+            .as_deref()
+            .unwrap_or("width=device-width, initial-scale=1");
+        let viewport_node =
+            (document && !options.omit_viewport).then(|| meta_name("viewport", viewport));
+
+        let title_node = title.as_deref().filter(|_| document).map(|title| Node {
+            name: "title",
+            attr: Vec::new(),
+            children: Vec::new(),
+            text: title.into(),
+        });
+
+        let tag_link_nodes = match kind {
+            Some(OpenGraphType::Article(article)) if options.emit_tag_links => article
+                .tag
+                .iter()
+                .filter_map(|tag| {
+                    Some(Node {
+                        name: "link",
+                        attr: vec![
+                            ("rel".into(), "tag".into()),
+                            ("href".into(), tag.url.as_deref()?.into()),
+                        ],
+                        children: Vec::new(),
+                        text: None.into(),
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let breadcrumbs_node = if options.breadcrumbs_json_ld {
+            self.breadcrumbs_json_ld().map(|json| Node {
+                name: "script",
+                attr: vec![("type".into(), "application/ld+json".into())],
+                children: Vec::new(),
+                text: json.into(),
+            })
+        } else {
+            None
+        };
+
+        let keywords_node = match kind {
+            Some(OpenGraphType::Article(article)) if options.emit_keywords => {
+                let keywords = article
+                    .section
+                    .iter()
+                    .map(String::as_str)
+                    .chain(article.tag.iter().map(|tag| tag.name.as_str()))
+                    .collect::<Vec<_>>();
+
+                (!keywords.is_empty()).then(|| Node {
+                    name: "meta",
+                    attr: vec![
+                        ("name".into(), "keywords".into()),
+                        ("content".into(), keywords.join(", ").into()),
+                    ],
+                    children: Vec::new(),
+                    text: None.into(),
+                })
+            }
+            _ => None,
+        };
+
+        let kind = as_ref(kind).or(profile.as_ref().map(|_| "profile"));
+
+        let open_graph_nodes = merge(
+            open_graph_nodes_opt![
+                ("og:title", title),
+                ("og:type", kind),
+                ("og:url", url),
+                ("og:image", image),
+                ("og:image:type", image_type),
+                ("og:image:width", image_width),
+                ("og:image:height", image_height),
+            ],
+            merge(
+                merged_image_nodes,
+                images
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, image)| Some(*i) != duplicate && !image.url.is_empty())
+                    .flat_map(|(_, image)| image.to_nodes())
+                    .collect(),
+            ),
+        );
+
+        let open_graph_nodes = merge(
+            open_graph_nodes,
+            merge(
+                audio.iter().flat_map(Audio::to_nodes).collect(),
+                video.iter().flat_map(Video::to_nodes).collect(),
+            ),
+        );
+
+        let open_graph_nodes = merge(
+            open_graph_nodes,
+            open_graph_nodes_opt![
+                ("og:description", description),
+                ("og:determiner", determiner),
+                ("og:locale", locale),
+                ("og:site_name", site_name),
+                ("og:updated_time", updated_time),
+            ],
+        );
+
+        let open_graph_nodes = merge(
+            open_graph_nodes,
+            open_graph_nodes_vec![
+                ("og:locale:alternate", alternate_locale),
+                ("og:see_also", see_also),
+            ],
+        );
+
+        let base_node = base_href.as_deref().map(|href| Node {
+            name: "base",
+            attr: vec![("href".into(), href.into())],
+            children: Vec::new(),
+            text: None.into(),
+        });
+
+        let property_nodes = merge(
+            merge(open_graph_nodes, nodes),
+            extra
+                .iter()
+                .map(|(property, content)| meta_property(property, content))
+                .collect(),
+        );
+
+        let property_nodes = if options.emits(Family::OpenGraph) {
+            property_nodes
+        } else {
+            Vec::new()
+        };
+
+        let property_nodes = if options.required_first {
+            required_first(property_nodes)
+        } else {
+            property_nodes
+        };
+
+        let property_nodes = if options.percent_encode_urls {
+            property_nodes
+                .into_iter()
+                .map(|mut node| {
+                    if node.property().is_some_and(|x| URL_PROPERTIES.contains(&x)) {
+                        for (key, value) in node.attr.iter_mut() {
+                            if key == "content" {
+                                *value = percent_encode_url(value).into();
+                            }
+                        }
+                    }
+                    node
+                })
+                .collect()
+        } else {
+            property_nodes
+        };
+
+        let charset_node = Node {
+            name: "meta",
+            attr: vec![("charset".into(), "utf-8".into())],
+            children: Vec::new(),
+            text: None.into(),
+        };
+
+        let children = if options.amp {
+            let children = append_opt(vec![charset_node], viewport_node);
+            let children = append_opt(children, base_node);
+
+            merge(children, property_nodes)
+        } else if options.charset_first {
+            let children = append_opt(vec![charset_node], base_node);
+            let children = merge(children, property_nodes);
+
+            append_opt(children, viewport_node)
+        } else {
+            let children = append(
+                merge(append_opt(Vec::new(), base_node), property_nodes),
+                charset_node,
+            );
+
+            append_opt(children, viewport_node)
+        };
+        let children = append_opt(children, title_node);
+        let children = match theme_color_dark.as_deref() {
+            // Discord reads a single theme-color for the accent of the embed, and ignores the media
+            Some(theme_color_dark) if options.discord_embed => append(
+                children,
+                meta_name(
+                    "theme-color",
+                    theme_color.as_deref().unwrap_or(theme_color_dark),
+                ),
+            ),
+            Some(theme_color_dark) => {
+                let theme_color_node = |scheme: &str, color| Node {
+                    name: "meta",
+                    attr: vec![
+                        ("name".into(), "theme-color".into()),
+                        (
+                            "media".into(),
+                            format!("(prefers-color-scheme: {scheme})").into(),
+                        ),
+                        ("content".into(), color),
+                    ],
+                    children: Vec::new(),
+                    text: None.into(),
+                };
+
+                let children = append_opt(
+                    children,
+                    theme_color
+                        .as_deref()
+                        .map(|color| theme_color_node("light", color.into())),
+                );
+                append(children, theme_color_node("dark", theme_color_dark.into()))
+            }
+            None => append_opt(
+                children,
+                theme_color
+                    .as_deref()
+                    .map(|color| meta_name("theme-color", color)),
+            ),
+        };
+        let children = append_opt(
+            children,
+            referrer_policy
+                .as_deref()
+                .map(|policy| meta_name("referrer", policy)),
+        );
+        let children = append_opt(
+            children,
+            robots.as_deref().map(|robots| meta_name("robots", robots)),
+        );
+        let children = append_opt(
+            children,
+            author_name
+                .as_deref()
+                .map(|author| meta_name("author", author)),
+        );
+        let children = append_opt(children, keywords_node);
+        let children = merge(children, tag_link_nodes);
+        let children = append_opt(children, image_src_node);
+        let children = append_opt(children, breadcrumbs_node);
+
+        let children = match &options.data_managed_attr {
+            Some(value) => children
+                .into_iter()
+                .map(|mut node| {
+                    if node.name == "meta" {
+                        node.attr
+                            .push(("data-managed".into(), value.clone().into()));
+                    }
+                    node
+                })
+                .collect(),
+            None => children,
+        };
+
+        Node {
+            name: "head",
+            attr: Vec::new(),
+            children,
+            text: None.into(),
+        }
+    }
+}
+
+/// The properties whose contents are urls.
+const URL_PROPERTIES: &[&str] = &[
+    "og:url",
+    "og:image",
+    "og:image:url",
+    "og:image:secure_url",
+    "og:audio",
+    "og:audio:secure_url",
+    "og:video",
+    "og:video:secure_url",
+    "og:see_also",
+    "article:author",
+    "book:author",
+    "music:song",
+    "music:creator",
+    "video:actor",
+    "video:director",
+    "video:writer",
+];
+
+/// The characters that aren't allowed in the path and query of a url. `%` is kept, so encoded urls aren't encoded twice.
+const URL_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'|')
+    .add(b'\\')
+    .add(b'^');
+
+/// Leaves the scheme and host as is.
+fn percent_encode_url(url: &str) -> String {
+    let host_end = url
+        .find("://")
+        .map(|i| i + 3)
+        .map(|start| {
+            url[start..]
+                .find(['/', '?', '#'])
+                .map_or(url.len(), |end| start + end)
+        })
+        .unwrap_or(0);
+
+    let (origin, rest) = url.split_at(host_end);
+
+    format!("{origin}{}", utf8_percent_encode(rest, URL_ENCODE_SET))
+}
+
+/// Uses `RenderOptions::namespace_overrides` for the prefix if any, and the scheme of `RenderOptions::http_namespaces`
+/// for the `ogp.me` namespaces. Other uris are left as is.
+fn namespace_uri<'a>(prefix: &str, uri: &'a str, options: &RenderOptions) -> Cow<'a, str> {
+    if let Some((_, uri)) = options
+        .namespace_overrides
+        .iter()
+        .find(|(x, _)| x == prefix)
+    {
+        return uri.clone().into();
+    }
+
+    let (from, to) = if options.http_namespaces {
+        ("https://ogp.me/", "http://ogp.me/")
+    } else {
+        ("http://ogp.me/", "https://ogp.me/")
+    };
+
+    match uri.strip_prefix(from) {
+        Some(path) => format!("{to}{path}").into(),
+        None => uri.into(),
+    }
+}
+
+macro_rules! iso8601 {
+    [$timezone:expr; $($x:ident$(,)?)*] => {
+        let timezone: Option<chrono::FixedOffset> = $timezone;
+        $(
+            let $x = $x.map(|x| match timezone {
+                Some(timezone) => x.with_timezone(&timezone).to_rfc3339(),
+                None => x.to_rfc3339(),
+            });
+        )*
+    };
+    [$($x:ident$(,)?)*] => {
+        $crate::iso8601![None; $($x,)*]
+    };
+}
+
+pub(crate) use iso8601;
+
+/// Moves the first of each required property to the front, keeping the order of the others.
+///
+/// The `og:image:*` properties following an `og:image` are moved together with it.
+fn required_first(nodes: Vec<Node<'_>>) -> Vec<Node<'_>> {
+    let mut groups: Vec<Vec<Node>> = Vec::new();
+
+    for node in nodes {
+        match (node.property(), groups.last_mut()) {
+            (Some(property), Some(group))
+                if property.starts_with("og:image:") && group[0].property() == Some("og:image") =>
+            {
+                group.push(node)
+            }
+            _ => groups.push(vec![node]),
+        }
+    }
+
+    let mut required = Vec::new();
+
+    for property in ["og:title", "og:type", "og:url", "og:image"] {
+        if let Some(i) = groups
+            .iter()
+            .position(|group| group[0].property() == Some(property))
+        {
+            required.push(groups.remove(i));
+        }
+    }
+
+    required.into_iter().chain(groups).flatten().collect()
+}
+
+fn strip_query_params(x: &mut String, params: &[&str]) {
+    let Ok(mut url) = url::Url::parse(x) else {
+        return;
+    };
+
+    let Some(query) = url.query() else {
+        return;
+    };
+
+    // the kept pairs are left as they are written, e.g. `a` isn't rewritten to `a=`
+    let segments = query.split('&').collect::<Vec<_>>();
+    let kept = segments
+        .iter()
+        .filter(|segment| {
+            url::form_urlencoded::parse(segment.as_bytes())
+                .next()
+                .is_none_or(|(key, _)| !params.contains(&key.as_ref()))
+        })
+        .copied()
+        .collect::<Vec<_>>();
+
+    if kept.len() == segments.len() {
+        return;
+    }
+
+    let query = kept.join("&");
+    url.set_query((!query.is_empty()).then_some(query.as_str()));
+
+    *x = url.into();
+}
+
+fn meta_property<'a>(property: &'a str, content: &'a str) -> Node<'a> {
+    Node {
+        name: "meta",
+        attr: vec![
+            ("property".into(), property.into()),
+            ("content".into(), content.into()),
+        ],
+        children: Vec::new(),
+        text: None.into(),
+    }
+}
+
+fn meta_name<'a>(name: &'static str, content: &'a str) -> Node<'a> {
+    Node {
+        name: "meta",
+        attr: vec![
+            ("name".into(), name.into()),
+            ("content".into(), content.into()),
+        ],
+        children: Vec::new(),
+        text: None.into(),
+    }
+}
+
+/// A json string literal. `<` is escaped too, so the json can be put in a `<script>`.
+pub(crate) fn json_string(x: &str) -> String {
+    let mut r = String::from('"');
+
+    for c in x.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            '\n' => r.push_str("\\n"),
+            '\r' => r.push_str("\\r"),
+            '\t' => r.push_str("\\t"),
+            '<' => r.push_str("\\u003c"),
+            c if c.is_control() => r.push_str(&format!("\\u{:04x}", c as u32)),
+            c => r.push(c),
+        }
+    }
+
+    r.push('"');
+    r
+}
 
 pub fn merge<T>(mut xs: Vec<T>, mut ys: Vec<T>) -> Vec<T> {
     xs.append(&mut ys);
@@ -264,6 +1692,10 @@ where
     pub fn is_none(&self) -> bool {
         self.0.is_none()
     }
+
+    pub fn as_deref(&self) -> Option<&T> {
+        self.0.as_deref()
+    }
 }
 
 impl<'a, T> From<&'a T> for OptionalCow<'a, T>
@@ -290,9 +1722,13 @@ where
     }
 }
 
+/// An html element.
+///
+/// All fields are public, so a node returned from e.g. [`OpenGraph::head_node`]
+/// can be walked and converted into the elements of another dom builder.
 pub struct Node<'a> {
     pub name: &'static str,
-    pub attr: Vec<(&'static str, Cow<'a, str>)>,
+    pub attr: Vec<(Cow<'a, str>, Cow<'a, str>)>,
     pub children: Vec<Node<'a>>,
     pub text: OptionalCow<'a, str>,
 }
@@ -309,44 +1745,150 @@ impl Default for Node<'_> {
 }
 
 impl<'a> Node<'a> {
-    fn to_html(&self) -> String {
+    pub(crate) fn property(&self) -> Option<&str> {
+        self.attr
+            .iter()
+            .find(|(key, _)| key == "property")
+            .map(|(_, value)| value.as_ref())
+    }
+
+    fn render(&self, options: &RenderOptions) -> String {
         let mut r = String::new();
 
-        r.push('<');
-        r.push_str(self.name);
-        for (key, value) in self.attr.iter() {
-            r.push(' ');
-            r.push_str(key);
-            r.push('=');
-            r.push('\"');
-            r.push_str(value);
-            r.push('\"');
+        // can't exceed `usize::MAX`
+        let _ = self.write_html(&mut r, options, 0, usize::MAX);
+
+        r
+    }
+
+    /// Fails as soon as `r` exceeds `max_bytes`.
+    fn render_bounded(&self, options: &RenderOptions, max_bytes: usize) -> Result<String, Error> {
+        let mut r = String::new();
+
+        self.write_html(&mut r, options, 0, max_bytes)?;
+
+        Ok(r)
+    }
+
+    /// Writes each element on its own line, indented by `options.indent` spaces per depth, if `options.pretty` is set.
+    fn write_html(
+        &self,
+        r: &mut String,
+        options: &RenderOptions,
+        depth: usize,
+        max_bytes: usize,
+    ) -> Result<(), Error> {
+        let indent = options.pretty.then_some(options.indent);
+
+        if let Some(indent) = indent {
+            if depth > 0 {
+                r.push('\n');
+            }
+            r.push_str(&" ".repeat(indent * depth));
         }
 
+        self.write_start_tag(r, options);
+
         if self.children.is_empty() && self.text.is_none() {
             r.push_str("/>");
 
-            return r;
+            return check_size(r, max_bytes);
         }
 
         r.push('>');
 
-        for children in self.children.iter() {
-            r.push_str(&children.to_html());
+        check_size(r, max_bytes)?;
+
+        for (i, children) in self.children.iter().enumerate() {
+            if options.newline_between_tags && indent.is_none() && self.name == "head" && i > 0 {
+                r.push('\n');
+            }
+
+            children.write_html(r, options, depth + 1, max_bytes)?;
         }
 
         if let OptionalCow(Some(text)) = &self.text {
-            r.push_str(text);
+            // the json of a `<script>` is escaped by `json_string`, and entities aren't decoded there
+            if self.name == "script" {
+                r.push_str(text);
+            } else {
+                r.push_str(&escape_text(text));
+            }
+        }
+
+        if let Some(indent) = indent.filter(|_| !self.children.is_empty()) {
+            r.push('\n');
+            r.push_str(&" ".repeat(indent * depth));
         }
 
         r.push_str("</");
         r.push_str(self.name);
         r.push('>');
 
+        check_size(r, max_bytes)
+    }
+
+    /// Writes `<name attr="value"`, without closing the tag.
+    fn write_start_tag(&self, r: &mut String, options: &RenderOptions) {
+        let quote = if options.single_quote_attrs {
+            '\''
+        } else {
+            '"'
+        };
+
+        r.push('<');
+        r.push_str(self.name);
+        for (key, value) in self.attr.iter() {
+            r.push(' ');
+            r.push_str(key);
+            r.push('=');
+            r.push(quote);
+            r.push_str(&escape_attr(value, quote));
+            r.push(quote);
+        }
+    }
+
+    fn start_tag(&self, options: &RenderOptions) -> String {
+        let mut r = String::new();
+
+        self.write_start_tag(&mut r, options);
+        r.push('>');
+
         r
     }
 }
 
+/// Escapes `&`, `<` and the `quote` of the attribute, so a value can't end its attribute or start an element.
+fn escape_attr(x: &str, quote: char) -> Cow<'_, str> {
+    if !x.contains(['&', '<', quote]) {
+        return x.into();
+    }
+
+    let escaped_quote = if quote == '\'' { "&#39;" } else { "&quot;" };
+
+    x.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace(quote, escaped_quote)
+        .into()
+}
+
+/// Escapes `&` and `<`, so a text can't start an element.
+fn escape_text(x: &str) -> Cow<'_, str> {
+    if !x.contains(['&', '<']) {
+        return x.into();
+    }
+
+    x.replace('&', "&amp;").replace('<', "&lt;").into()
+}
+
+fn check_size(r: &str, max_bytes: usize) -> Result<(), Error> {
+    if r.len() > max_bytes {
+        Err(Error::OutputTooLarge)
+    } else {
+        Ok(())
+    }
+}
+
 #[test]
 fn test_to_html() {
     let og = OpenGraph {
@@ -410,18 +1952,7 @@ fn test_to_html_with_fallback_node() {
 
 #[test]
 fn test_profile() {
-    let og = OpenGraph {
-        title: "Syrflover".to_owned().into(),
-        description: "madome developer".to_owned().into(),
-        kind: OpenGraphType::Profile(Profile {
-            first_name: "Lee".to_owned().into(),
-            last_name: "TaeWoo".to_owned().into(),
-            username: "syrflover".to_owned().into(),
-            gender: profile::Gender::Male.into(),
-        })
-        .into(),
-        ..Default::default()
-    };
+    let og = fixtures::example_profile();
 
     let html = og.to_html();
 
@@ -435,26 +1966,1682 @@ fn test_profile() {
 
 #[test]
 fn test_article() {
+    let og = fixtures::example_article();
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# article: https://ogp.me/ns/article#"><head><meta property="og:title" content="why can't fly"/><meta property="og:type" content="article"/><meta property="article:published_time" content="2022-12-19T07:39:57+00:00"/><meta property="article:modified_time" content="2023-03-12T02:25:33+00:00"/><meta property="article:expiration_time" content="2024-05-02T15:00:00+00:00"/><meta property="article:section" content="Nothing"/><meta property="article:author" content="https://og.example.com/@syrflover"/><meta property="article:tag" content="chicken"/><meta property="article:tag" content="food"/><meta property="article:tag" content="fry"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_article_time_offset() {
     let og = OpenGraph {
-        title: "why can't fly".to_owned().into(),
         kind: OpenGraphType::Article(Article {
             published_time: Some("2022-12-19T16:39:57+09:00".parse().unwrap()),
-            modified_time: Some("2023-03-12T11:25:33+09:00".parse().unwrap()),
-            expiration_time: Some("2024-05-03T00:00:00+09:00".parse().unwrap()),
-            author: vec!["https://og.example.com/@syrflover".to_owned()],
-            section: "Nothing".to_owned().into(),
-            tag: vec!["chicken".to_owned(), "food".to_owned(), "fry".to_owned()],
+            ..Default::default()
         })
         .into(),
         ..Default::default()
     };
 
+    let html = og.to_html_with_options(&RenderOptions {
+        keep_offsets: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(
+        r#"<meta property="article:published_time" content="2022-12-19T16:39:57+09:00"/>"#
+    ));
+
+    assert!(og.to_html().contains(
+        r#"<meta property="article:published_time" content="2022-12-19T07:39:57+00:00"/>"#
+    ));
+}
+
+#[test]
+fn test_sync_updated_time() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        kind: OpenGraphType::Article(Article {
+            modified_time: Some("2023-03-12T11:25:33+09:00".parse().unwrap()),
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        sync_updated_time: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(
+        html.contains(r#"<meta property="og:updated_time" content="2023-03-12T02:25:33+00:00"/>"#)
+    );
+    assert!(html.contains(
+        r#"<meta property="article:modified_time" content="2023-03-12T02:25:33+00:00"/>"#
+    ));
+
+    assert!(!og.to_html().contains("og:updated_time"));
+}
+
+#[test]
+fn test_to_document_pretty() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        description: "this is open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_document_with_options(&RenderOptions {
+        pretty: true,
+        indent: 2,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<!DOCTYPE html>
+<html prefix="og: https://ogp.me/ns#">
+  <head>
+    <meta property="og:title" content="open graph"/>
+    <meta property="og:description" content="this is open graph"/>
+    <meta charset="utf-8"/>
+    <meta name="viewport" content="width=device-width, initial-scale=1"/>
+    <title>open graph</title>
+  </head>
+</html>"#
+    );
+
+    fixtures::assert_og_html_eq(&html, &og.to_document());
+}
+
+#[test]
+fn test_profile_gender_raw() {
+    let og = OpenGraph {
+        kind: OpenGraphType::Profile(Profile {
+            gender: profile::Gender::Male.into(),
+            gender_raw: "non-binary".to_owned().into(),
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="profile:gender" content="non-binary"/>"#));
+    assert!(!html.contains(r#"content="male""#));
+
+    let og = OpenGraph {
+        kind: OpenGraphType::Profile(Profile::default()).into(),
+        ..Default::default()
+    };
+
+    assert!(!og.to_html().contains("profile:gender"));
+}
+
+#[test]
+fn test_robots() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        robots: "index, follow".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta name="robots" content="index, follow"/>"#));
+    assert_eq!(og.validate(), Ok(vec![]));
+
+    let og = OpenGraph {
+        robots: "noindex, max-snippet:50, nofolow".to_owned().into(),
+        ..og
+    };
+
+    assert_eq!(
+        og.validate(),
+        Ok(vec![validate::ValidationWarning::UnknownRobotsDirective(
+            "nofolow".to_owned()
+        )])
+    );
+}
+
+#[test]
+fn test_referrer_policy() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        referrer_policy: "no-referrer".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta name="referrer" content="no-referrer"/>"#));
+    assert!(og.validate().is_ok());
+
+    let og = OpenGraph {
+        referrer_policy: "nobody".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og.validate(),
+        Err(validate::ValidationError::InvalidReferrerPolicy(
+            "nobody".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn test_document_viewport() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_document();
+
+    println!("{html}");
+
+    assert!(
+        html.contains(r#"<meta name="viewport" content="width=device-width, initial-scale=1"/>"#)
+    );
+    assert!(!og.to_html().contains("viewport"));
+
+    let html = og.to_document_with_options(&RenderOptions {
+        omit_viewport: true,
+        ..Default::default()
+    });
+
+    assert!(!html.contains("viewport"));
+
+    let og = OpenGraph {
+        viewport: "width=1024".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert!(og
+        .to_document()
+        .contains(r#"<meta name="viewport" content="width=1024"/>"#));
+}
+
+#[test]
+fn test_image_type() {
+    let og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        image_type: "image/png".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:image" content="https://og.example.com/chicken.png"/><meta property="og:image:type" content="image/png"/>"#));
+
+    let og = OpenGraph {
+        image_type: "image/png".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert!(!og.to_html().contains("og:image:type"));
+}
+
+#[test]
+fn test_head_node() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let head = og.head_node();
+
+    assert_eq!(head.name, "head");
+    assert_eq!(head.children.len(), 2);
+    assert_eq!(head.children[0].text.as_deref(), None);
+
+    let title = Node {
+        name: "title",
+        text: "open graph".into(),
+        ..Default::default()
+    };
+
+    assert_eq!(title.text.as_deref(), Some("open graph"));
+}
+
+#[test]
+fn test_alternate_locale_joined() {
+    let og = OpenGraph {
+        locale: "en_US".to_owned().into(),
+        alternate_locale: vec!["ko_KR".to_owned(), "ja_JP".to_owned()],
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        alternate_locale_joined: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:locale:alternate" content="ko_KR,ja_JP"/>"#));
+    assert_eq!(html.matches("og:locale:alternate").count(), 1);
+
+    assert_eq!(og.to_html().matches("og:locale:alternate").count(), 2);
+}
+
+#[test]
+fn test_strip_query_params() {
+    let mut og = OpenGraph {
+        url: "https://og.example.com/?utm_source=twitter"
+            .to_owned()
+            .into(),
+        image: "https://og.example.com/chicken.png?w=1200&utm_source=twitter&h=630"
+            .to_owned()
+            .into(),
+        default_image: "https://og.example.com/default.png?fbclid=1"
+            .to_owned()
+            .into(),
+        images: vec![Image {
+            url: "http://og.example.com/egg.png?utm_source=twitter".to_owned(),
+            secure_url: "https://og.example.com/egg.png?utm_source=twitter"
+                .to_owned()
+                .into(),
+            ..Default::default()
+        }],
+        see_also: vec!["https://og.example.com/fry?fbclid=1".to_owned()],
+        breadcrumbs: vec![(
+            "Recipes".to_owned(),
+            "https://og.example.com/recipes?fbclid=1".to_owned(),
+        )],
+        kind: OpenGraphType::Article(Article {
+            tag: vec![article::Tag {
+                name: "chicken".to_owned(),
+                url: "https://og.example.com/tags/chicken?utm_source=twitter"
+                    .to_owned()
+                    .into(),
+            }],
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    og.strip_query_params(&["utm_source", "fbclid"]);
+
+    assert_eq!(og.see_also, vec!["https://og.example.com/fry".to_owned()]);
+    assert_eq!(
+        og.default_image.as_deref(),
+        Some("https://og.example.com/default.png")
+    );
+    assert_eq!(og.images[0].url, "http://og.example.com/egg.png");
+    assert_eq!(
+        og.images[0].secure_url.as_deref(),
+        Some("https://og.example.com/egg.png")
+    );
+    assert_eq!(og.breadcrumbs[0].1, "https://og.example.com/recipes");
+    let Some(OpenGraphType::Article(article)) = &og.kind else {
+        unreachable!()
+    };
+    assert_eq!(
+        article.tag[0].url.as_deref(),
+        Some("https://og.example.com/tags/chicken")
+    );
+
+    assert_eq!(og.url.as_deref(), Some("https://og.example.com/"));
+    assert_eq!(
+        og.image.as_deref(),
+        Some("https://og.example.com/chicken.png?w=1200&h=630")
+    );
+
+    let mut og = OpenGraph {
+        url: "https://og.example.com/?a&b=x%2By&c=1+2".to_owned().into(),
+        image: "https://og.example.com/chicken.png?a&utm_source=twitter&c=1+2"
+            .to_owned()
+            .into(),
+        ..Default::default()
+    };
+
+    og.strip_query_params(&["utm_source"]);
+
+    assert_eq!(
+        og.url.as_deref(),
+        Some("https://og.example.com/?a&b=x%2By&c=1+2")
+    );
+    assert_eq!(
+        og.image.as_deref(),
+        Some("https://og.example.com/chicken.png?a&c=1+2")
+    );
+}
+
+#[test]
+fn test_base_href() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        base_href: "https://og.example.com/".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_document();
+
+    println!("{html}");
+
+    assert!(html.contains(
+        r#"<head><base href="https://og.example.com/"/><meta property="og:title" content="open graph"/>"#
+    ));
+}
+
+#[test]
+fn test_images() {
+    let og = OpenGraph {
+        image: "https://og.example.com/logo.png".to_owned().into(),
+        images: vec![Image::new("https://og.example.com/chicken.png").size(1200, 630)],
+        description: "this is open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:image" content="https://og.example.com/logo.png"/><meta property="og:image" content="https://og.example.com/chicken.png"/><meta property="og:image:width" content="1200"/><meta property="og:image:height" content="630"/><meta property="og:description" content="this is open graph"/>"#));
+}
+
+#[test]
+fn test_images_empty_url() {
+    let og = OpenGraph {
+        images: vec![
+            Image::new("https://og.example.com/chicken.png"),
+            Image::new("").size(1200, 630),
+        ],
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(html.matches("og:image").count(), 1);
+    assert!(html
+        .contains(r#"<meta property="og:image" content="https://og.example.com/chicken.png"/>"#));
+}
+
+#[test]
+fn test_image_for() {
+    let og = OpenGraph {
+        images: vec![
+            Image::new("https://og.example.com/square.png").size(600, 600),
+            Image::new("https://og.example.com/wide.png").size(1200, 628),
+            Image::new("https://og.example.com/unknown.png"),
+        ],
+        ..Default::default()
+    };
+
+    let image = og.image_for(1.91).unwrap();
+
+    assert_eq!(image.url, "https://og.example.com/wide.png");
+
+    let image = og.image_for(1.0).unwrap();
+
+    assert_eq!(image.url, "https://og.example.com/square.png");
+}
+
+#[test]
+fn test_music_playlist() {
+    let og = OpenGraph {
+        title: "chicken songs".to_owned().into(),
+        kind: OpenGraphType::MusicPlaylist(Playlist {
+            song: vec!["https://og.example.com/songs/1".to_owned()],
+            creator: vec!["https://og.example.com/@syrflover".to_owned()],
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# music: https://ogp.me/ns/music#"><head><meta property="og:title" content="chicken songs"/><meta property="og:type" content="music.playlist"/><meta property="music:song" content="https://og.example.com/songs/1"/><meta property="music:creator" content="https://og.example.com/@syrflover"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_music_radio_station() {
+    let og = OpenGraph {
+        title: "chicken radio".to_owned().into(),
+        kind: OpenGraphType::MusicRadioStation(RadioStation {
+            creator: vec!["https://og.example.com/@syrflover".to_owned()],
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# music: https://ogp.me/ns/music#"><head><meta property="og:title" content="chicken radio"/><meta property="og:type" content="music.radio_station"/><meta property="music:creator" content="https://og.example.com/@syrflover"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_video_other() {
+    let og = OpenGraph {
+        title: "how to fry".to_owned().into(),
+        kind: OpenGraphType::VideoOther(VideoCommon {
+            actor: vec!["https://og.example.com/@syrflover".to_owned()],
+            duration: 600.into(),
+            release_date: Some("2022-12-19T16:39:57+09:00".parse().unwrap()),
+            tag: vec!["chicken".to_owned()],
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# video: https://ogp.me/ns/video#"><head><meta property="og:title" content="how to fry"/><meta property="og:type" content="video.other"/><meta property="video:actor" content="https://og.example.com/@syrflover"/><meta property="video:duration" content="600"/><meta property="video:release_date" content="2022-12-19T07:39:57+00:00"/><meta property="video:tag" content="chicken"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_video_movie_with_video_metadata() {
+    let og = OpenGraph {
+        title: "how to fry".to_owned().into(),
+        kind: OpenGraphType::VideoMovie(VideoCommon {
+            duration: 600.into(),
+            ..Default::default()
+        })
+        .into(),
+        video: vec![Video {
+            tag: vec!["chicken".to_owned()],
+            ..Video::from("https://og.example.com/fry.mp4")
+        }],
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# video: https://ogp.me/ns/video#"><head><meta property="og:title" content="how to fry"/><meta property="og:type" content="video.movie"/><meta property="og:video" content="https://og.example.com/fry.mp4"/><meta property="video:tag" content="chicken"/><meta property="video:duration" content="600"/><meta charset="utf-8"/></head></html>"#
+    );
+
+    let og = OpenGraph { kind: None, ..og };
+
+    assert!(og
+        .to_html()
+        .starts_with(r#"<html prefix="og: https://ogp.me/ns# video: https://ogp.me/ns/video#">"#));
+}
+
+#[test]
+fn test_extension() {
+    #[derive(Debug)]
+    struct Restaurant {
+        price_rating: u32,
+    }
+
+    impl OgNodes for Restaurant {
+        fn to_nodes(&self) -> Vec<Node<'_>> {
+            vec![Node {
+                name: "meta",
+                attr: vec![
+                    ("property".into(), "restaurant:price_rating".into()),
+                    ("content".into(), self.price_rating.to_string().into()),
+                ],
+                children: Vec::new(),
+                text: None.into(),
+            }]
+        }
+
+        fn namespace(&self) -> Option<(&'static str, &'static str)> {
+            Some(("restaurant", "https://ogp.me/ns/restaurant#"))
+        }
+    }
+
+    let og = OpenGraph {
+        title: "chicken house".to_owned().into(),
+        kind: OpenGraphType::Extension {
+            type_str: "restaurant.restaurant".to_owned(),
+            object: Arc::new(Restaurant { price_rating: 2 }),
+        }
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# restaurant: https://ogp.me/ns/restaurant#"><head><meta property="og:title" content="chicken house"/><meta property="og:type" content="restaurant.restaurant"/><meta property="restaurant:price_rating" content="2"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_custom_namespace() {
+    let mut og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        extra: vec![("myns:foo".to_owned(), "bar".to_owned())],
+        ..Default::default()
+    };
+
+    og.register_namespace("myns", "https://myns.example.com/ns#");
+    og.register_namespace("unused", "https://unused.example.com/ns#");
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# myns: https://myns.example.com/ns#"><head><meta property="og:title" content="open graph"/><meta property="myns:foo" content="bar"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_http_namespaces() {
+    let og = fixtures::example_article();
+
+    let html = og.to_html();
+
+    assert!(html.starts_with(
+        r#"<html prefix="og: https://ogp.me/ns# article: https://ogp.me/ns/article#">"#
+    ));
+
+    let html = og.to_html_with_options(&RenderOptions {
+        http_namespaces: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.starts_with(
+        r#"<html prefix="og: http://ogp.me/ns# article: http://ogp.me/ns/article#">"#
+    ));
+}
+
+#[test]
+fn test_namespace_overrides() {
+    let og = fixtures::example_article();
+
+    let options = RenderOptions {
+        namespace_overrides: vec![(
+            "article".to_owned(),
+            "https://ns.example.com/article#".to_owned(),
+        )],
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&options);
+
+    println!("{html}");
+
+    assert!(html.starts_with(
+        r#"<html prefix="og: https://ogp.me/ns# article: https://ns.example.com/article#">"#
+    ));
+
+    let html = og.to_html_with_options(&RenderOptions {
+        rdfa_xmlns: true,
+        ..options
+    });
+
+    assert!(html.starts_with(
+        r#"<html xmlns:og="https://ogp.me/ns#" xmlns:article="https://ns.example.com/article#">"#
+    ));
+}
+
+#[test]
+fn test_html_attrs() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        html_attrs: vec![("class".to_owned(), "og".to_owned())],
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.starts_with(r#"<html prefix="og: https://ogp.me/ns#" class="og"><head>"#));
+}
+
+#[test]
+fn test_data_managed_attr() {
+    let og = fixtures::example_profile();
+
+    let html = og.to_document_with_options(&RenderOptions {
+        data_managed_attr: Some("og".to_owned()),
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert_eq!(
+        html.matches("<meta ").count(),
+        html.matches(r#" data-managed="og"/>"#).count()
+    );
+    assert!(html.contains(r#"<meta property="og:title" content="Syrflover" data-managed="og"/>"#));
+    assert!(html.contains(r#"<title>Syrflover</title>"#));
+}
+
+#[test]
+fn test_add_custom() {
+    let mut og = OpenGraph {
+        title: "chicken songs".to_owned().into(),
+        ..Default::default()
+    };
+
+    og.add_custom(
+        "music",
+        "https://ogp.me/ns/music#",
+        "release_date",
+        "2022-12-19",
+    );
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# music: https://ogp.me/ns/music#"><head><meta property="og:title" content="chicken songs"/><meta property="music:release_date" content="2022-12-19"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_default_image() {
+    let og = OpenGraph {
+        default_image: "https://og.example.com/logo.png".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(
+        html.contains(r#"<meta property="og:image" content="https://og.example.com/logo.png"/>"#)
+    );
+
+    let og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        default_image: "https://og.example.com/logo.png".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    assert!(html
+        .contains(r#"<meta property="og:image" content="https://og.example.com/chicken.png"/>"#));
+    assert!(!html.contains("logo.png"));
+}
+
+#[test]
+fn test_with_fallback_image_if_missing() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        ..Default::default()
+    }
+    .with_fallback_image_if_missing("https://og.example.com/logo.png");
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(
+        html.contains(r#"<meta property="og:image" content="https://og.example.com/logo.png"/>"#)
+    );
+    assert_eq!(
+        og.validate(),
+        Ok(vec![validate::ValidationWarning::FallbackImage(
+            "https://og.example.com/logo.png".to_owned()
+        )])
+    );
+
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        images: vec![Image::new("https://og.example.com/chicken.png").alt("chicken")],
+        ..Default::default()
+    }
+    .with_fallback_image_if_missing("https://og.example.com/logo.png");
+
+    let html = og.to_html();
+
+    assert!(!html.contains("logo.png"));
+    assert_eq!(og.default_image, None);
+    assert_eq!(og.validate(), Ok(Vec::new()));
+
+    let og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        ..Default::default()
+    }
+    .with_fallback_image_if_missing("https://og.example.com/logo.png");
+
+    assert_eq!(og.default_image, None);
+
+    let og = OpenGraph {
+        default_image: "https://og.example.com/fry.png".to_owned().into(),
+        ..Default::default()
+    }
+    .with_fallback_image_if_missing("https://og.example.com/logo.png");
+
+    assert_eq!(
+        og.default_image.as_deref(),
+        Some("https://og.example.com/fry.png")
+    );
+}
+
+#[test]
+fn test_duplicate_image() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        image_width: 1200.into(),
+        image_height: 630.into(),
+        images: vec![
+            Image::new("https://og.example.com/fry.png"),
+            Image::new("https://og.example.com/chicken.png").alt("a fried chicken"),
+        ],
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(html.matches("chicken.png").count(), 1);
+    assert!(html.contains(r#"<meta property="og:image" content="https://og.example.com/chicken.png"/><meta property="og:image:width" content="1200"/><meta property="og:image:height" content="630"/><meta property="og:image:alt" content="a fried chicken"/><meta property="og:image" content="https://og.example.com/fry.png"/>"#));
+}
+
+#[test]
+fn test_open_graph_macro() {
+    let og = open_graph! {
+        title: "why can't fly",
+        kind: OpenGraphType::Website,
+        url: "https://og.example.com/chicken".to_owned(),
+        image: "https://og.example.com/chicken.png",
+        image_width: 1200,
+        alternate_locale: vec!["ko_KR"],
+    };
+
+    let expected = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        kind: OpenGraphType::Website.into(),
+        url: "https://og.example.com/chicken".to_owned().into(),
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        image_width: 1200.into(),
+        alternate_locale: vec!["ko_KR".to_owned()],
+        ..Default::default()
+    };
+
+    assert_eq!(og.to_html(), expected.to_html());
+}
+
+#[test]
+fn test_book() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        kind: OpenGraphType::Book(Book {
+            author: vec!["https://og.example.com/@syrflover".into()],
+            isbn: "978-3-16-148410-0".to_owned().into(),
+            release_date: Some("2022-12-19T16:39:57+09:00".parse().unwrap()),
+            tag: vec!["chicken".to_owned()],
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# book: https://ogp.me/ns/book#"><head><meta property="og:title" content="why can't fly"/><meta property="og:type" content="book"/><meta property="book:author" content="https://og.example.com/@syrflover"/><meta property="book:isbn" content="978-3-16-148410-0"/><meta property="book:release_date" content="2022-12-19T07:39:57+00:00"/><meta property="book:tag" content="chicken"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_to_view() {
+    let og = fixtures::example_article();
+
+    let view = og.to_view();
+
+    assert_eq!(view.head_html, og.to_meta_tags_html());
+    assert_eq!(view.tags.len(), 11);
+    assert_eq!(
+        view.tags[0],
+        r#"<meta property="og:title" content="why can't fly"/>"#
+    );
+}
+
+#[test]
+fn test_image_dimensions() {
+    let og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        image_width: 1200.into(),
+        image_height: 630.into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:image" content="https://og.example.com/chicken.png"/><meta property="og:image:width" content="1200"/><meta property="og:image:height" content="630"/>"#));
+
+    let og = OpenGraph {
+        image_width: 1200.into(),
+        image_height: 630.into(),
+        ..Default::default()
+    };
+
+    assert!(!og.to_html().contains("og:image:width"));
+    assert_eq!(
+        og.validate(),
+        Ok(vec![
+            validate::ValidationWarning::ImageDimensionsWithoutImage
+        ])
+    );
+}
+
+#[test]
+fn test_author_name() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        author_name: "Lee TaeWoo".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta name="author" content="Lee TaeWoo"/>"#));
+}
+
+#[test]
+fn test_emit_keywords() {
+    let og = fixtures::example_article();
+
+    let html = og.to_html_with_options(&RenderOptions {
+        emit_keywords: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta name="keywords" content="Nothing, chicken, food, fry"/>"#));
+    assert!(!og.to_html().contains("keywords"));
+}
+
+#[test]
+fn test_emit_keywords_section() {
+    let og = OpenGraph {
+        kind: OpenGraphType::Article(Article {
+            section: "Technology".to_owned().into(),
+            tag: vec!["rust".into()],
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        emit_keywords: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta name="keywords" content="Technology, rust"/>"#));
+}
+
+#[test]
+fn test_emit_tag_links() {
+    let mut og = fixtures::example_article();
+
+    if let Some(OpenGraphType::Article(article)) = &mut og.kind {
+        article.tag[0] = article::Tag::new("chicken", "https://og.example.com/tags/chicken");
+    }
+
+    let html = og.to_html_with_options(&RenderOptions {
+        emit_tag_links: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="article:tag" content="chicken"/><meta property="article:tag" content="food"/>"#));
+    assert!(html.contains(r#"<link rel="tag" href="https://og.example.com/tags/chicken"/></head>"#));
+    assert_eq!(html.matches("<link").count(), 1);
+    assert!(!og.to_html().contains("<link"));
+}
+
+#[test]
+fn test_etag() {
+    let og = fixtures::example_article();
+
+    assert_eq!(og.etag(), fixtures::example_article().etag());
+    assert_eq!(og.etag().len(), 18);
+
+    let other = OpenGraph {
+        title: "why can fly".to_owned().into(),
+        ..fixtures::example_article()
+    };
+
+    assert_ne!(og.etag(), other.etag());
+}
+
+#[test]
+fn test_to_crawler_page() {
+    let og = fixtures::example_profile();
+
+    let html = og.to_crawler_page("Syrflover on og.example.com");
+
+    println!("{html}");
+
+    assert!(html.starts_with("<!DOCTYPE html><html"));
+    assert!(html.contains(r#"<meta property="og:title" content="Syrflover"/>"#));
+    assert!(html.contains("<title>Syrflover</title>"));
+    assert!(html.ends_with("</head><body>Syrflover on og.example.com</body></html>"));
+}
+
+#[test]
+fn test_newline_between_tags() {
+    let og = fixtures::example_profile();
+
+    let html = og.to_html_with_options(&RenderOptions {
+        newline_between_tags: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(concat!(
+        r#"<head><meta property="og:title" content="Syrflover"/>"#,
+        "\n",
+        r#"<meta property="og:type" content="profile"/>"#,
+        "\n",
+    )));
+    assert!(html.ends_with(concat!("/>\n", r#"<meta charset="utf-8"/></head></html>"#)));
+    assert_eq!(html.replace('\n', ""), og.to_html());
+}
+
+#[test]
+fn test_theme_color_dark() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        theme_color: "#4285f4".to_owned().into(),
+        theme_color_dark: "#202124".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r##"<meta name="theme-color" media="(prefers-color-scheme: light)" content="#4285f4"/><meta name="theme-color" media="(prefers-color-scheme: dark)" content="#202124"/>"##));
+    assert_eq!(html.matches("theme-color").count(), 2);
+}
+
+#[test]
+fn test_to_html_collection() {
+    let item = |title: &str, url: &str| OpenGraph {
+        title: title.to_owned().into(),
+        url: url.to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = OpenGraph::to_html_collection(&[
+        item("chicken", "https://og.example.com/chicken"),
+        item("fry", "https://og.example.com/fry"),
+        item("food", "https://og.example.com/food"),
+    ]);
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:title" content="chicken"/><meta property="og:url" content="https://og.example.com/chicken"/><meta property="og:see_also" content="https://og.example.com/fry"/><meta property="og:see_also" content="https://og.example.com/food"/>"#));
+    assert_eq!(html.matches("og:title").count(), 1);
+}
+
+#[test]
+fn test_discord_embed() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        theme_color: "#4285f4".to_owned().into(),
+        theme_color_dark: "#202124".to_owned().into(),
+        ..Default::default()
+    };
+
+    let options = RenderOptions {
+        discord_embed: true,
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&options);
+
+    println!("{html}");
+
+    assert!(html.contains(r##"<meta name="theme-color" content="#4285f4"/>"##));
+    assert_eq!(html.matches("theme-color").count(), 1);
+}
+
+#[test]
+fn test_timezone() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        kind: Some(OpenGraphType::Article(Article {
+            published_time: Some("2022-12-19T07:39:57+00:00".parse().unwrap()),
+            ..Default::default()
+        })),
+        updated_time: Some("2023-03-12T02:25:33+00:00".parse().unwrap()),
+        extra: vec![(
+            "og:updated_time".to_owned(),
+            "2023-03-12T02:25:33+00:00".to_owned(),
+        )],
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        timezone: FixedOffset::east_opt(9 * 3600),
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(
+        r#"<meta property="article:published_time" content="2022-12-19T16:39:57+09:00"/>"#
+    ));
+    assert!(
+        html.contains(r#"<meta property="og:updated_time" content="2023-03-12T11:25:33+09:00"/>"#)
+    );
+    // `extra` is rendered as is
+    assert!(
+        html.contains(r#"<meta property="og:updated_time" content="2023-03-12T02:25:33+00:00"/>"#)
+    );
+
+    let html = og.to_html();
+
+    assert!(html.contains(
+        r#"<meta property="article:published_time" content="2022-12-19T07:39:57+00:00"/>"#
+    ));
+}
+
+#[test]
+fn test_breadcrumbs() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        breadcrumbs: vec![
+            ("Home".to_owned(), "https://og.example.com".to_owned()),
+            ("Food".to_owned(), "https://og.example.com/food".to_owned()),
+            (
+                "Chicken".to_owned(),
+                "https://og.example.com/food/chicken".to_owned(),
+            ),
+        ],
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        breadcrumbs_json_ld: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<script type="application/ld+json">{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":[{"@type":"ListItem","position":1,"name":"Home","item":"https://og.example.com"},{"@type":"ListItem","position":2,"name":"Food","item":"https://og.example.com/food"},{"@type":"ListItem","position":3,"name":"Chicken","item":"https://og.example.com/food/chicken"}]}</script>"#));
+
+    let html = og.to_html();
+
+    assert!(!html.contains("breadcrumb"));
+    assert!(!html.contains("ld+json"));
+}
+
+#[test]
+fn test_emit_image_src_link() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        images: vec![
+            Image::new("https://og.example.com/chicken.png"),
+            Image::new("https://og.example.com/fry.png"),
+        ],
+        ..Default::default()
+    };
+    let options = RenderOptions {
+        emit_image_src_link: true,
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&options);
+
+    println!("{html}");
+
+    assert!(html
+        .contains(r#"<meta property="og:image" content="https://og.example.com/chicken.png"/>"#));
+    assert!(html.contains(r#"<link rel="image_src" href="https://og.example.com/chicken.png"/>"#));
+    assert!(!html.contains(r#"href="https://og.example.com/fry.png""#));
+
+    let html = og.to_html();
+
+    assert!(!html.contains("image_src"));
+}
+
+#[test]
+fn test_render_chunks() {
+    let og = fixtures::example_article();
+
+    let chunks = og.render_chunks().collect::<Vec<_>>();
+
+    println!("{chunks:#?}");
+
+    assert_eq!(chunks[0], "<!DOCTYPE html>");
+    assert_eq!(chunks[2], "<head>");
+    assert_eq!(chunks.last().map(String::as_str), Some("</html>"));
+    assert_eq!(chunks.concat(), og.to_document());
+}
+
+#[test]
+fn test_resolve_determiner() {
+    let og = OpenGraph {
+        title: "apple".to_owned().into(),
+        determiner: "auto".to_owned().into(),
+        ..Default::default()
+    };
+    let options = RenderOptions {
+        resolve_determiner: true,
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&options);
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:determiner" content="an"/>"#));
+    assert!(og
+        .to_html()
+        .contains(r#"<meta property="og:determiner" content="auto"/>"#));
+
+    let og = OpenGraph {
+        title: "chicken".to_owned().into(),
+        ..og
+    };
+
+    assert_eq!(og.resolve_determiner(), Some("a"));
+}
+
+#[test]
+fn test_to_meta_nodes() {
+    let og = OpenGraph {
+        base_href: "https://og.example.com/".to_owned().into(),
+        ..fixtures::example_article()
+    };
+
+    let nodes = og.to_meta_nodes();
+
+    assert_eq!(nodes.len(), 11);
+    assert!(nodes.iter().all(|node| node.name == "meta"));
+    assert_eq!(nodes[0].property(), Some("og:title"));
+}
+
+#[test]
+fn test_to_template_html() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        url: "https://og.example.com/chicken".to_owned().into(),
+        theme_color: "#4285f4".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_template_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<template><meta property="og:title" content="why can't fly"/><meta property="og:url" content="https://og.example.com/chicken"/></template>"#
+    );
+}
+
+#[test]
+fn test_single_quote_attrs() {
+    let og = OpenGraph {
+        title: "why can't fly".to_owned().into(),
+        description: r#"a "fried" <chicken> & beer"#.to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        single_quote_attrs: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix='og: https://ogp.me/ns#'><head><meta property='og:title' content='why can&#39;t fly'/><meta property='og:description' content='a "fried" &lt;chicken> &amp; beer'/><meta charset='utf-8'/></head></html>"#
+    );
+
+    fixtures::assert_og_html_eq(&html, &og.to_html());
+}
+
+#[test]
+fn test_to_html_bytes() {
+    let og = fixtures::example_article();
+
+    assert_eq!(og.to_html_bytes(), og.to_html().into_bytes());
+}
+
+#[test]
+fn test_clear_image() {
+    let mut og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        image_type: "image/png".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert!(og.to_html().contains("og:image"));
+
+    og.clear_image();
+
+    assert!(!og.to_html().contains("og:image"));
+}
+
+#[test]
+fn test_required_first() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        description: "this is open graph".to_owned().into(),
+        images: vec![Image::new("https://og.example.com/chicken.png").size(1200, 630)],
+        extra: vec![("og:url".to_owned(), "https://og.example.com/".to_owned())],
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        required_first: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<head><meta property="og:title" content="open graph"/><meta property="og:url" content="https://og.example.com/"/><meta property="og:image" content="https://og.example.com/chicken.png"/><meta property="og:image:width" content="1200"/><meta property="og:image:height" content="630"/><meta property="og:description" content="this is open graph"/><meta charset="utf-8"/>"#));
+}
+
+#[test]
+fn test_website() {
+    let og = OpenGraph::website(
+        "open graph",
+        "https://og.example.com/",
+        "https://og.example.com/logo.png",
+    );
+
+    assert!(og.validate().is_ok());
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns#"><head><meta property="og:title" content="open graph"/><meta property="og:type" content="website"/><meta property="og:url" content="https://og.example.com/"/><meta property="og:image" content="https://og.example.com/logo.png"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_profile_without_kind() {
+    let og = OpenGraph {
+        title: "Syrflover".to_owned().into(),
+        profile: Profile {
+            username: "syrflover".to_owned().into(),
+            ..Default::default()
+        }
+        .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# profile: https://ogp.me/ns/profile#"><head><meta property="og:title" content="Syrflover"/><meta property="og:type" content="profile"/><meta property="profile:username" content="syrflover"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_videos() {
+    let og = OpenGraph {
+        title: "how to fry".to_owned().into(),
+        video: vec![
+            Video {
+                url: "https://og.example.com/fry.mp4".to_owned(),
+                mime_type: "video/mp4".to_owned().into(),
+                width: 1280.into(),
+                height: 720.into(),
+                ..Default::default()
+            },
+            "https://og.example.com/fry.webm".into(),
+        ],
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:video" content="https://og.example.com/fry.mp4"/><meta property="og:video:type" content="video/mp4"/><meta property="og:video:width" content="1280"/><meta property="og:video:height" content="720"/><meta property="og:video" content="https://og.example.com/fry.webm"/>"#));
+}
+
+#[test]
+fn test_to_html_with_fallback_in() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_fallback_in("noscript", "fallback message");
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r##"<html prefix="og: https://ogp.me/ns#"><head><meta property="og:title" content="open graph"/><meta charset="utf-8"/></head><body><noscript>fallback message</noscript></body></html>"##
+    )
+}
+
+#[test]
+fn test_rdfa_xmlns() {
+    let og = fixtures::example_article();
+
+    let html = og.to_html_with_options(&RenderOptions {
+        rdfa_xmlns: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.starts_with(
+        r#"<html xmlns:og="https://ogp.me/ns#" xmlns:article="https://ogp.me/ns/article#"><head>"#
+    ));
+}
+
+#[test]
+fn test_borrows_fields() {
+    let og = fixtures::example_article();
+
+    let head = og.head_node();
+
+    let title = head
+        .children
+        .iter()
+        .find(|node| node.property() == Some("og:title"))
+        .unwrap();
+
+    let Cow::Borrowed(content) = &title.attr[1].1 else {
+        panic!("expected the title to be borrowed");
+    };
+
+    assert_eq!(content.as_ptr(), og.title.as_ref().unwrap().as_ptr());
+}
+
+#[test]
+fn test_to_debug_comment() {
+    let og = OpenGraph {
+        title: "open graph --> closed --!> graph ---".to_owned().into(),
+        description: "this is open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let comment = og.to_debug_comment();
+
+    println!("{comment}");
+
+    assert_eq!(
+        comment,
+        "<!-- og:\nog:title: open graph - -> closed - -!> graph - - -\nog:description: this is open graph\n-->"
+    );
+    assert!(!comment[4..comment.len() - 3].contains("--"));
+}
+
+#[test]
+fn test_amp() {
+    let og = OpenGraph {
+        base_href: "https://og.example.com/".to_owned().into(),
+        ..fixtures::example_profile()
+    };
+
+    let html = og.to_document_with_options(&RenderOptions {
+        amp: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<head><meta charset="utf-8"/><meta name="viewport" content="width=device-width, initial-scale=1"/><base href="https://og.example.com/"/><meta property="og:title" content="Syrflover"/>"#));
+    assert_eq!(html.matches("charset").count(), 1);
+}
+
+#[test]
+fn test_charset_first() {
+    let og = OpenGraph {
+        base_href: "https://og.example.com/".to_owned().into(),
+        ..fixtures::example_profile()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        charset_first: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<head><meta charset="utf-8"/><base href="https://og.example.com/"/><meta property="og:title" content="Syrflover"/>"#));
+    assert_eq!(html.matches("charset").count(), 1);
+}
+
+#[test]
+fn test_is_empty() {
+    assert!(OpenGraph::default().is_empty());
+
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert!(!og.is_empty());
+
+    let og = OpenGraph {
+        image_width: 1200.into(),
+        images: vec![Image::new("")],
+        ..Default::default()
+    };
+
+    assert!(og.is_empty());
+}
+
+#[test]
+fn test_percent_encode_urls() {
+    let og = OpenGraph {
+        title: "fried chicken".to_owned().into(),
+        url: "https://og.example.com/fried chicken?q=양념 치킨"
+            .to_owned()
+            .into(),
+        image: "https://og.example.com/fried%20chicken.png"
+            .to_owned()
+            .into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        percent_encode_urls: true,
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:title" content="fried chicken"/>"#));
+    assert!(html.contains(r#"<meta property="og:url" content="https://og.example.com/fried%20chicken?q=%EC%96%91%EB%85%90%20%EC%B9%98%ED%82%A8"/>"#));
+    assert!(html.contains(
+        r#"<meta property="og:image" content="https://og.example.com/fried%20chicken.png"/>"#
+    ));
+
+    let og = OpenGraph {
+        url: "https://og.example.com?q=fried chicken".to_owned().into(),
+        ..Default::default()
+    };
+
+    let html = og.to_html_with_options(&RenderOptions {
+        percent_encode_urls: true,
+        ..Default::default()
+    });
+
+    assert!(html.contains(
+        r#"<meta property="og:url" content="https://og.example.com?q=fried%20chicken"/>"#
+    ));
+}
+
+#[test]
+fn test_to_patch() {
+    let previous = fixtures::example_article();
+
+    let mut og = fixtures::example_article();
+    og.title = "why can fly".to_owned().into();
+    if let Some(OpenGraphType::Article(article)) = &mut og.kind {
+        article.tag.pop();
+    }
+
+    assert_eq!(
+        og.to_patch(&previous),
+        vec![
+            ("og:title".to_owned(), Some("why can fly".to_owned())),
+            ("article:tag".to_owned(), Some("chicken".to_owned())),
+            ("article:tag".to_owned(), Some("food".to_owned())),
+        ]
+    );
+
+    if let Some(OpenGraphType::Article(article)) = &mut og.kind {
+        article.clear_tags();
+    }
+
+    assert_eq!(
+        og.to_patch(&previous),
+        vec![
+            ("og:title".to_owned(), Some("why can fly".to_owned())),
+            ("article:tag".to_owned(), None),
+        ]
+    );
+    assert!(og.to_patch(&og).is_empty());
+}
+
+#[test]
+fn test_social_preview() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        images: vec![Image::new("https://og.example.com/chicken.png")],
+        default_image: "https://og.example.com/logo.png".to_owned().into(),
+        ..Default::default()
+    };
+
+    let preview = og.social_preview();
+
+    assert_eq!(preview.title.as_deref(), Some("open graph"));
+    assert_eq!(
+        preview.image.as_deref(),
+        Some("https://og.example.com/chicken.png")
+    );
+}
+
+#[test]
+fn test_to_html_bounded() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        extra: (0..1000)
+            .map(|i| ("og:tag".to_owned(), i.to_string()))
+            .collect(),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        og.to_html_bounded(256),
+        Err(Error::OutputTooLarge)
+    ));
+
+    let html = og.to_html();
+
+    assert_eq!(og.to_html_bounded(html.len()).unwrap(), html);
+}
+
+#[test]
+fn test_with_default_locale() {
+    let og = OpenGraph::default().with_default_locale("en_US");
+
+    assert_eq!(og.locale.as_deref(), Some("en_US"));
+
+    let og = OpenGraph {
+        locale: "ko_KR".to_owned().into(),
+        ..Default::default()
+    }
+    .with_default_locale("en_US");
+
+    assert_eq!(og.locale.as_deref(), Some("ko_KR"));
+}
+
+#[test]
+fn test_custom_type() {
+    let og = OpenGraph {
+        title: "chicken house".to_owned().into(),
+        kind: OpenGraphType::Custom {
+            type_str: "restaurant.restaurant".to_owned(),
+            namespace: Some((
+                "restaurant".to_owned(),
+                "http://ogp.me/ns/restaurant#".to_owned(),
+            )),
+        }
+        .into(),
+        extra: vec![("restaurant:price_rating".to_owned(), "2".to_owned())],
+        ..Default::default()
+    };
+
     let html = og.to_html();
 
     println!("{html}");
 
     assert_eq!(
         html,
-        r#"<html prefix="og: https://ogp.me/ns# article: http://ogp.me/ns/article#"><head><meta property="og:title" content="why can't fly"/><meta property="og:type" content="article"/><meta property="article:published_time" content="2022-12-19T07:39:57+00:00"/><meta property="article:modified_time" content="2023-03-12T02:25:33+00:00"/><meta property="article:expiration_time" content="2024-05-02T15:00:00+00:00"/><meta property="article:section" content="Nothing"/><meta property="article:author" content="https://og.example.com/@syrflover"/><meta property="article:tag" content="chicken"/><meta property="article:tag" content="food"/><meta property="article:tag" content="fry"/><meta charset="utf-8"/></head></html>"#
+        r#"<html prefix="og: https://ogp.me/ns# restaurant: https://ogp.me/ns/restaurant#"><head><meta property="og:title" content="chicken house"/><meta property="og:type" content="restaurant.restaurant"/><meta property="restaurant:price_rating" content="2"/><meta charset="utf-8"/></head></html>"#
     );
 }
@@ -0,0 +1,48 @@
+/// The MIME type of a url or path, guessed from its file extension.
+pub fn mime_from_extension(url_or_path: &str) -> Option<&'static str> {
+    let path = url_or_path.split(['?', '#']).next().unwrap_or(url_or_path);
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let (_, extension) = file_name.rsplit_once('.')?;
+
+    let mime = match extension.to_ascii_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        "ico" => "image/x-icon",
+        "mp4" | "m4v" => "video/mp4",
+        "webm" => "video/webm",
+        "ogv" => "video/ogg",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "ogg" | "oga" => "audio/ogg",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "aac" => "audio/aac",
+        _ => return None,
+    };
+
+    Some(mime)
+}
+
+#[test]
+fn test_mime_from_extension() {
+    assert_eq!(
+        mime_from_extension("https://og.example.com/chicken.png"),
+        Some("image/png")
+    );
+    assert_eq!(
+        mime_from_extension("https://og.example.com/chicken.MP4?t=10"),
+        Some("video/mp4")
+    );
+    assert_eq!(mime_from_extension("songs/chicken.mp3"), Some("audio/mpeg"));
+    assert_eq!(
+        mime_from_extension("https://og.example.com/chicken.xyz"),
+        None
+    );
+    assert_eq!(mime_from_extension("https://og.example.com/chicken"), None);
+}
@@ -0,0 +1,42 @@
+use crate::{open_graph_nodes_vec, Node, OgNodes};
+
+pub(crate) const NAMESPACE: (&str, &str) = ("music", "https://ogp.me/ns/music#");
+
+#[derive(Debug, Clone, Default)]
+pub struct Playlist {
+    /// URLs of the songs on the playlist.
+    pub song: Vec<String>,
+
+    /// The creators of the playlist.
+    pub creator: Vec<String>,
+}
+
+impl OgNodes for Playlist {
+    fn to_nodes(&self) -> Vec<Node<'_>> {
+        let Playlist { song, creator } = self;
+
+        open_graph_nodes_vec![("music:song", song), ("music:creator", creator)]
+    }
+
+    fn namespace(&self) -> Option<(&'static str, &'static str)> {
+        Some(NAMESPACE)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RadioStation {
+    /// The creators of the radio station.
+    pub creator: Vec<String>,
+}
+
+impl OgNodes for RadioStation {
+    fn to_nodes(&self) -> Vec<Node<'_>> {
+        let RadioStation { creator } = self;
+
+        open_graph_nodes_vec![("music:creator", creator)]
+    }
+
+    fn namespace(&self) -> Option<(&'static str, &'static str)> {
+        Some(NAMESPACE)
+    }
+}
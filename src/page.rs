@@ -0,0 +1,127 @@
+use crate::{
+    append_opt, merge, meta_name, twitter::TwitterCard, Family, Node, OpenGraph, RenderOptions,
+};
+
+/// The open graph, twitter and standard metadata of a page, rendered as one head without duplicates.
+#[derive(Debug, Clone, Default)]
+pub struct PageMeta {
+    pub open_graph: OpenGraph,
+
+    /// Its unset fields fall back to the open graph properties, e.g. `twitter:title` to `og:title`.
+    pub twitter: Option<TwitterCard>,
+
+    /// The `<title>`. `og:title` if not set.
+    pub title: Option<String>,
+
+    /// `<meta name="description">`. `og:description` if not set.
+    pub description: Option<String>,
+
+    /// `<meta name="author">`. `OpenGraph::author_name` if not set.
+    pub author: Option<String>,
+}
+
+impl PageMeta {
+    pub fn to_html(&self) -> String {
+        self.to_html_with_options(&RenderOptions::default())
+    }
+
+    pub fn to_html_with_options(&self, options: &RenderOptions) -> String {
+        let og = OpenGraph {
+            author_name: self
+                .author
+                .clone()
+                .or_else(|| self.open_graph.author_name.clone()),
+            ..self.open_graph.clone()
+        };
+
+        let title = self.title.as_deref().or(og.title.as_deref());
+        let description = self.description.as_deref().or(og.description.as_deref());
+        let twitter = self
+            .twitter
+            .as_ref()
+            .filter(|_| options.emits(Family::Twitter))
+            .map(|x| x.with_fallback(&og));
+
+        let mut html = og.to_node(None, options, false);
+        let head = &mut html.children[0];
+
+        let title_node = title.map(|title| Node {
+            name: "title",
+            attr: Vec::new(),
+            children: Vec::new(),
+            text: title.into(),
+        });
+        let description_node = description.map(|x| meta_name("description", x));
+
+        let children = append_opt(std::mem::take(&mut head.children), title_node);
+        let children = append_opt(children, description_node);
+        head.children = merge(
+            children,
+            twitter
+                .as_ref()
+                .map(TwitterCard::to_nodes)
+                .unwrap_or_default(),
+        );
+
+        html.render(options)
+    }
+}
+
+#[test]
+fn test_page_meta() {
+    use crate::twitter::Card;
+
+    let meta = PageMeta {
+        open_graph: OpenGraph {
+            title: "why can't fly".to_owned().into(),
+            description: "a chicken".to_owned().into(),
+            image: "https://og.example.com/chicken.png".to_owned().into(),
+            ..Default::default()
+        },
+        twitter: TwitterCard {
+            card: Card::SummaryLargeImage.into(),
+            site: "@syrflover".to_owned().into(),
+            ..Default::default()
+        }
+        .into(),
+        ..Default::default()
+    };
+
+    let html = meta.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<title>why can't fly</title><meta name="description" content="a chicken"/><meta name="twitter:card" content="summary_large_image"/><meta name="twitter:site" content="@syrflover"/><meta name="twitter:title" content="why can't fly"/><meta name="twitter:description" content="a chicken"/><meta name="twitter:image" content="https://og.example.com/chicken.png"/>"#));
+    assert_eq!(html.matches("<title>").count(), 1);
+}
+
+#[test]
+fn test_page_meta_families() {
+    let meta = PageMeta {
+        open_graph: OpenGraph {
+            title: "why can't fly".to_owned().into(),
+            image: "https://og.example.com/chicken.png".to_owned().into(),
+            ..Default::default()
+        },
+        twitter: TwitterCard::default().into(),
+        ..Default::default()
+    };
+
+    let html = meta.to_html_with_options(&RenderOptions {
+        families: [Family::Twitter].into(),
+        ..Default::default()
+    });
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta name="twitter:title" content="why can't fly"/>"#));
+    assert!(!html.contains("og:"));
+
+    let html = meta.to_html_with_options(&RenderOptions {
+        families: [Family::OpenGraph].into(),
+        ..Default::default()
+    });
+
+    assert!(html.contains(r#"<meta property="og:title" content="why can't fly"/>"#));
+    assert!(!html.contains("twitter:"));
+}
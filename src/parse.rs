@@ -0,0 +1,838 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    article::Article,
+    audio::Audio,
+    book::Book,
+    image::Image,
+    music::{Playlist, RadioStation},
+    profile::Profile,
+    video::{Video, VideoCommon},
+    OpenGraph, OpenGraphType,
+};
+
+/// The namespaces known to the parser, without the scheme of the uri.
+const NAMESPACES: &[(&str, &str)] = &[
+    ("og", "ogp.me/ns#"),
+    ("article", "ogp.me/ns/article#"),
+    ("profile", "ogp.me/ns/profile#"),
+    ("book", "ogp.me/ns/book#"),
+    ("music", "ogp.me/ns/music#"),
+    ("video", "ogp.me/ns/video#"),
+];
+
+#[derive(Debug, Clone)]
+pub struct Parsed {
+    pub open_graph: OpenGraph,
+
+    /// The namespaces declared in the `prefix` of `<html>`, e.g. `("og", "https://ogp.me/ns#")`.
+    pub namespaces: Vec<(String, String)>,
+
+    /// Problems in the html that were worked around.
+    pub warnings: Vec<ParseWarning>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// There is more than one `og:type`. The first one is used, and the given one is ignored.
+    MultipleTypes(String),
+
+    /// The `og:type` isn't known and its prefix isn't declared, so the type is inferred from the properties
+    /// of the built-in namespaces, e.g. `article` from `article:section`, and the given one is ignored.
+    UnknownType(String),
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::MultipleTypes(x) => write!(f, "ignored additional og:type: {x}"),
+            ParseWarning::UnknownType(x) => write!(f, "ignored unknown og:type: {x}"),
+        }
+    }
+}
+
+struct Tag<'a> {
+    name: String,
+
+    /// The unescaped values.
+    attr: Vec<(String, Cow<'a, str>)>,
+}
+
+impl Tag<'_> {
+    fn get(&self, key: &str) -> Option<String> {
+        self.attr
+            .iter()
+            .find(|(x, _)| x == key)
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+impl OpenGraph {
+    /// Extracts the open graph properties from the `<meta>` tags of the html.
+    ///
+    /// The properties that aren't fields of `OpenGraph` or of its `kind` are kept in `extra`,
+    /// and the unknown namespaces in `namespaces`, so they are emitted again by `to_html`.
+    pub fn from_html(html: &str) -> Parsed {
+        from_tags(tags(html))
+    }
+
+    /// Like [`OpenGraph::from_html`], for a document already parsed by `scraper`.
+    #[cfg(feature = "scraper")]
+    pub fn from_scraper(doc: &scraper::Html) -> OpenGraph {
+        let tags = doc.tree.values().filter_map(|node| {
+            let element = node.as_element()?;
+
+            Some(Tag {
+                name: element.name().to_ascii_lowercase(),
+                attr: element
+                    .attrs()
+                    .map(|(key, value)| (key.to_ascii_lowercase(), value.into()))
+                    .collect(),
+            })
+        });
+
+        from_tags(tags).open_graph
+    }
+
+    /// Extracts the url and the image from the values of `Link` headers, for responses without html.
+    ///
+    /// `rel="canonical"` is the `url`, `rel="image_src"` the `image`,
+    /// and `rel="icon"` or `rel="apple-touch-icon"` the `default_image`.
+    pub fn from_link_headers(headers: &[String]) -> OpenGraph {
+        let mut og = OpenGraph::default();
+
+        for (url, params) in headers.iter().flat_map(|header| links(header)) {
+            let rel = params
+                .split(';')
+                .filter_map(|param| param.split_once('='))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case("rel"))
+                .map(|(_, value)| value.trim().trim_matches('"'))
+                .unwrap_or_default();
+
+            for rel in rel.split_ascii_whitespace() {
+                match rel.to_ascii_lowercase().as_str() {
+                    "canonical" => set(&mut og.url, url.to_owned()),
+                    "image_src" => set(&mut og.image, url.to_owned()),
+                    "icon" | "apple-touch-icon" => set(&mut og.default_image, url.to_owned()),
+                    _ => {}
+                }
+            }
+        }
+
+        og
+    }
+}
+
+/// Splits the value of a `Link` header into the urls and their parameters, e.g. `<url>; rel="canonical"`.
+fn links(header: &str) -> Vec<(&str, &str)> {
+    let mut links = Vec::new();
+    let mut rest = header;
+
+    while let Some(start) = rest.find('<') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('>') else {
+            break;
+        };
+        let url = after[..end].trim();
+        rest = &after[end + 1..];
+
+        let params_end = rest
+            .match_indices(',')
+            .map(|(i, _)| i)
+            .find(|&i| rest[i + 1..].trim_start().starts_with('<'))
+            .unwrap_or(rest.len());
+
+        links.push((url, &rest[..params_end]));
+        rest = &rest[params_end..];
+    }
+
+    links
+}
+
+/// Collects the properties of the `<meta>` tags, and the `prefix` of `<html>`.
+fn from_tags<'a>(tags: impl Iterator<Item = Tag<'a>>) -> Parsed {
+    let mut og = OpenGraph::default();
+    let mut warnings = Vec::new();
+    let mut properties = Vec::new();
+    let mut namespaces = Vec::new();
+
+    for tag in tags {
+        match tag.name.as_str() {
+            "html" => {
+                if let Some(prefix) = tag.get("prefix") {
+                    namespaces = parse_prefix(&prefix);
+                }
+            }
+            "meta" => {
+                let Some(content) = tag.get("content") else {
+                    continue;
+                };
+
+                if let Some(property) = tag.get("property") {
+                    properties.push((property, content));
+                    continue;
+                }
+
+                match tag.get("name").as_deref() {
+                    Some("theme-color") => match tag.get("media") {
+                        Some(media) if media.contains("dark") => {
+                            set(&mut og.theme_color_dark, content)
+                        }
+                        _ => set(&mut og.theme_color, content),
+                    },
+                    Some("referrer") => set(&mut og.referrer_policy, content),
+                    Some("robots") => set(&mut og.robots, content),
+                    Some("viewport") => set(&mut og.viewport, content),
+                    Some("author") => set(&mut og.author_name, content),
+                    _ => {}
+                }
+            }
+            "base" => {
+                if let Some(href) = tag.get("href") {
+                    set(&mut og.base_href, href);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let properties = properties
+        .into_iter()
+        .map(|(property, content)| (canonicalize(&namespaces, property), content))
+        .collect::<Vec<_>>();
+
+    // the kind is resolved first, so the properties of the type before `og:type` belong to it too
+    let declared_kind = properties
+        .iter()
+        .find(|(property, _)| property == "og:type")
+        .map(|(_, content)| kind(&namespaces, content.clone()));
+    let inferred_kind = properties
+        .iter()
+        .find_map(|(property, _)| inferred_kind(property));
+
+    og.kind = match (declared_kind, inferred_kind) {
+        (
+            Some(OpenGraphType::Custom {
+                type_str,
+                namespace: None,
+            }),
+            Some(inferred_kind),
+        ) => {
+            warnings.push(ParseWarning::UnknownType(type_str));
+            Some(inferred_kind)
+        }
+        (None, inferred_kind) => inferred_kind,
+        (declared_kind, _) => declared_kind,
+    };
+
+    let mut type_seen = false;
+
+    // whether the url of the last image came from `og:image`, so a different `og:image:url` of it is a mismatch
+    let mut image_from_og_image = false;
+
+    for (property, content) in properties {
+        match property.as_str() {
+            "og:type" if !type_seen => type_seen = true,
+            "og:type" => warnings.push(ParseWarning::MultipleTypes(content)),
+            "og:title" => set(&mut og.title, content),
+            "og:url" => set(&mut og.url, content),
+            "og:image" => {
+                match og.images.last_mut() {
+                    // the secure_url came first
+                    Some(image) if image.url.is_empty() => image.url = content,
+                    _ => og.images.push(Image::new(content)),
+                }
+                image_from_og_image = true;
+            }
+            "og:image:url" => match og.images.last_mut() {
+                Some(image) if image.url.is_empty() => {
+                    image.url = content;
+                    image_from_og_image = false;
+                }
+                Some(image) if image_from_og_image => {
+                    // kept for `lint`, which reports the mismatch
+                    if image.url != content {
+                        og.extra.push((property, content));
+                    }
+                }
+                _ => {
+                    og.images.push(Image::new(content));
+                    image_from_og_image = false;
+                }
+            },
+            "og:audio" | "og:audio:url" => og.audio.push(content.into()),
+            "og:video" | "og:video:url" => og.video.push(content.into()),
+            "og:description" => set(&mut og.description, content),
+            "og:determiner" => set(&mut og.determiner, content),
+            "og:locale" => set(&mut og.locale, content),
+            "og:locale:alternate" => og.alternate_locale.push(content),
+            "og:see_also" => og.see_also.push(content),
+            "og:site_name" => set(&mut og.site_name, content),
+            "og:updated_time" => set_time(&mut og.updated_time, &content),
+            "og:image:secure_url" if og.images.is_empty() => og.images.push(Image {
+                secure_url: Some(content),
+                ..Default::default()
+            }),
+            _ => {
+                let image = property.strip_prefix("og:image:").zip(og.images.last_mut());
+                let audio = property.strip_prefix("og:audio:").zip(og.audio.last_mut());
+                let video = property.strip_prefix("og:video:").zip(og.video.last_mut());
+
+                let rest = match (image, audio, video) {
+                    (Some((key, image)), _, _) => set_image_property(image, key, content),
+                    (_, Some((key, audio)), _) => set_audio_property(audio, key, content),
+                    (_, _, Some((key, video))) => set_video_property(video, key, content),
+                    _ => match og.kind.as_mut() {
+                        Some(kind) => set_kind_property(kind, &property, content),
+                        None => Some(content),
+                    },
+                };
+
+                if let Some(content) = rest {
+                    og.extra.push((property, content));
+                }
+            }
+        }
+    }
+
+    // an image with only a secure_url
+    for image in og.images.iter_mut().filter(|image| image.url.is_empty()) {
+        if let Some(secure_url) = &image.secure_url {
+            image.url = secure_url.clone();
+        }
+    }
+
+    for (prefix, uri) in &namespaces {
+        if known_namespace(uri).is_none() {
+            og.register_namespace(prefix, uri);
+        }
+    }
+
+    Parsed {
+        open_graph: og,
+        namespaces,
+        warnings,
+    }
+}
+
+/// Parses e.g. `og: https://ogp.me/ns# article: https://ogp.me/ns/article#`.
+fn parse_prefix(prefix: &str) -> Vec<(String, String)> {
+    let mut namespaces = Vec::new();
+    let mut words = prefix.split_whitespace();
+
+    while let Some(name) = words.next() {
+        let Some(name) = name.strip_suffix(':') else {
+            continue;
+        };
+
+        if let Some(uri) = words.next() {
+            namespaces.push((name.to_owned(), uri.to_owned()));
+        }
+    }
+
+    namespaces
+}
+
+fn known_namespace(uri: &str) -> Option<&'static (&'static str, &'static str)> {
+    let uri = uri
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+
+    NAMESPACES.iter().find(|(_, x)| *x == uri)
+}
+
+/// Renames the prefix of the property to the known one, if it is declared with a known namespace uri,
+/// e.g. `a:section` to `article:section` with `a: https://ogp.me/ns/article#`.
+fn canonicalize(namespaces: &[(String, String)], property: String) -> String {
+    let Some((prefix, rest)) = property.split_once(':') else {
+        return property;
+    };
+
+    let known = namespaces
+        .iter()
+        .find(|(name, _)| name == prefix)
+        .and_then(|(_, uri)| known_namespace(uri));
+
+    match known {
+        Some((name, _)) if *name != prefix => format!("{name}:{rest}"),
+        _ => property,
+    }
+}
+
+/// Types other than the known ones become `Custom`, with the declared namespace of their prefix, if any.
+fn kind(namespaces: &[(String, String)], x: String) -> OpenGraphType {
+    match x.as_str() {
+        "website" => OpenGraphType::Website,
+        "article" => OpenGraphType::Article(Article::default()),
+        "profile" => OpenGraphType::Profile(Profile::default()),
+        "book" => OpenGraphType::Book(Book::default()),
+        "music.playlist" => OpenGraphType::MusicPlaylist(Playlist::default()),
+        "music.radio_station" => OpenGraphType::MusicRadioStation(RadioStation::default()),
+        "video.movie" => OpenGraphType::VideoMovie(VideoCommon::default()),
+        "video.other" => OpenGraphType::VideoOther(VideoCommon::default()),
+        _ => {
+            let prefix = x.split('.').next().unwrap_or_default();
+
+            OpenGraphType::Custom {
+                namespace: namespaces.iter().find(|(name, _)| name == prefix).cloned(),
+                type_str: x,
+            }
+        }
+    }
+}
+
+/// The type of a property of the built-in namespaces that belong to a single type, e.g. `article:section`.
+fn inferred_kind(property: &str) -> Option<OpenGraphType> {
+    let (prefix, _) = property.split_once(':')?;
+
+    match prefix {
+        "article" => Some(OpenGraphType::Article(Article::default())),
+        "profile" => Some(OpenGraphType::Profile(Profile::default())),
+        "book" => Some(OpenGraphType::Book(Book::default())),
+        _ => None,
+    }
+}
+
+/// Sets the first value only.
+fn set(x: &mut Option<String>, value: String) {
+    if x.is_none() {
+        *x = Some(value);
+    }
+}
+
+fn set_time<Tz>(x: &mut Option<chrono::DateTime<Tz>>, value: &str)
+where
+    Tz: chrono::TimeZone,
+    chrono::DateTime<Tz>: std::str::FromStr,
+{
+    if x.is_none() {
+        *x = value.parse().ok();
+    }
+}
+
+fn set_number(x: &mut Option<u32>, value: &str) {
+    if x.is_none() {
+        *x = value.parse().ok();
+    }
+}
+
+/// Returns the content back if the key is unknown.
+fn set_image_property(image: &mut Image, key: &str, content: String) -> Option<String> {
+    match key {
+        "secure_url" => set(&mut image.secure_url, content),
+        "type" => set(&mut image.mime_type, content),
+        "width" => set_number(&mut image.width, &content),
+        "height" => set_number(&mut image.height, &content),
+        "alt" => set(&mut image.alt, content),
+        _ => return Some(content),
+    }
+
+    None
+}
+
+/// Returns the content back if the key is unknown.
+fn set_audio_property(audio: &mut Audio, key: &str, content: String) -> Option<String> {
+    match key {
+        "secure_url" => set(&mut audio.secure_url, content),
+        "type" => set(&mut audio.mime_type, content),
+        _ => return Some(content),
+    }
+
+    None
+}
+
+/// Returns the content back if the key is unknown.
+fn set_video_property(video: &mut Video, key: &str, content: String) -> Option<String> {
+    match key {
+        "secure_url" => set(&mut video.secure_url, content),
+        "type" => set(&mut video.mime_type, content),
+        "width" => set_number(&mut video.width, &content),
+        "height" => set_number(&mut video.height, &content),
+        _ => return Some(content),
+    }
+
+    None
+}
+
+/// Returns the content back if the property doesn't belong to the kind.
+fn set_kind_property(kind: &mut OpenGraphType, property: &str, content: String) -> Option<String> {
+    match (kind, property) {
+        (
+            OpenGraphType::Website | OpenGraphType::Custom { .. } | OpenGraphType::Extension { .. },
+            _,
+        ) => return Some(content),
+        (OpenGraphType::Article(article), property) => match property {
+            "article:published_time" => set_time(&mut article.published_time, &content),
+            "article:modified_time" => set_time(&mut article.modified_time, &content),
+            "article:expiration_time" => set_time(&mut article.expiration_time, &content),
+            "article:author" => article.author.push(content.into()),
+            "article:section" => set(&mut article.section, content),
+            "article:tag" => article.tag.push(content.into()),
+            "article:reading_time" => set_number(&mut article.reading_time_minutes, &content),
+            _ => return Some(content),
+        },
+        (OpenGraphType::Profile(profile), property) => match property {
+            "profile:first_name" => set(&mut profile.first_name, content),
+            "profile:last_name" => set(&mut profile.last_name, content),
+            "profile:username" => set(&mut profile.username, content),
+            "profile:gender" => match content.parse() {
+                Ok(gender) => profile.gender = profile.gender.or(Some(gender)),
+                Err(_) => set(&mut profile.gender_raw, content),
+            },
+            _ => return Some(content),
+        },
+        (OpenGraphType::Book(book), property) => match property {
+            "book:author" => book.author.push(content),
+            "book:isbn" => set(&mut book.isbn, content),
+            "book:release_date" => set_time(&mut book.release_date, &content),
+            "book:tag" => book.tag.push(content),
+            _ => return Some(content),
+        },
+        (OpenGraphType::MusicPlaylist(playlist), property) => match property {
+            "music:song" => playlist.song.push(content),
+            "music:creator" => playlist.creator.push(content),
+            _ => return Some(content),
+        },
+        (OpenGraphType::MusicRadioStation(radio_station), property) => match property {
+            "music:creator" => radio_station.creator.push(content),
+            _ => return Some(content),
+        },
+        (OpenGraphType::VideoMovie(video) | OpenGraphType::VideoOther(video), property) => {
+            match property {
+                "video:actor" => video.actor.push(content),
+                "video:director" => video.director.push(content),
+                "video:writer" => video.writer.push(content),
+                "video:duration" => set_number(&mut video.duration, &content),
+                "video:release_date" => set_time(&mut video.release_date, &content),
+                "video:tag" => video.tag.push(content),
+                _ => return Some(content),
+            }
+        }
+    }
+
+    None
+}
+
+/// The start tags of the html, with lowercased names and attribute keys.
+fn tags(html: &str) -> impl Iterator<Item = Tag<'_>> {
+    let mut rest = html;
+
+    std::iter::from_fn(move || loop {
+        let start = rest.find('<')?;
+        rest = &rest[start + 1..];
+
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let end = rest.find(|c: char| c.is_whitespace() || c == '>' || c == '/');
+        let (name, after) = rest.split_at(end.unwrap_or(rest.len()));
+
+        if name.is_empty() || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            continue;
+        }
+
+        let (attr, after) = attributes(after);
+        rest = after;
+
+        return Some(Tag {
+            name: name.to_ascii_lowercase(),
+            attr: attr
+                .into_iter()
+                .map(|(key, value)| (key, unescape(value).into()))
+                .collect(),
+        });
+    })
+}
+
+/// Parses the attributes up to the end of the tag, and returns the rest of the html.
+fn attributes(mut rest: &str) -> (Vec<(String, &str)>, &str) {
+    let mut attr = Vec::new();
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+
+        if rest.is_empty() {
+            return (attr, rest);
+        }
+
+        if let Some(after) = rest.strip_prefix('>') {
+            return (attr, after);
+        }
+
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        let key = rest[..end].to_ascii_lowercase();
+        rest = rest[end..].trim_start();
+
+        let Some(after) = rest.strip_prefix('=') else {
+            attr.push((key, ""));
+            continue;
+        };
+        rest = after.trim_start();
+
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let after = &rest[1..];
+                let end = after.find(quote).unwrap_or(after.len());
+                rest = after.get(end + 1..).unwrap_or_default();
+                &after[..end]
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(rest.len());
+                let value = &rest[..end];
+                rest = &rest[end..];
+                value
+            }
+        };
+
+        attr.push((key, value));
+    }
+}
+
+fn unescape(x: &str) -> String {
+    x.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[test]
+fn test_from_html() {
+    let html = crate::fixtures::example_article().to_html();
+
+    let Parsed {
+        open_graph,
+        warnings,
+        ..
+    } = OpenGraph::from_html(&html);
+
+    assert!(warnings.is_empty());
+    assert_eq!(open_graph.to_html(), html);
+}
+
+#[cfg(feature = "scraper")]
+#[test]
+fn test_from_scraper() {
+    let html = crate::fixtures::example_article().to_html();
+
+    let doc = scraper::Html::parse_document(&html);
+
+    let og = OpenGraph::from_scraper(&doc);
+
+    assert_eq!(og.title.as_deref(), Some("why can't fly"));
+    assert!(matches!(og.kind, Some(OpenGraphType::Article(_))));
+}
+
+#[test]
+fn test_from_html_multiple_types() {
+    let html = r#"<html><head>
+        <meta property="og:type" content="article">
+        <meta property="og:title" content="why can't fly">
+        <meta property="og:type" content="profile">
+        <meta property="article:section" content="Nothing">
+    </head></html>"#;
+
+    let Parsed {
+        open_graph,
+        warnings,
+        ..
+    } = OpenGraph::from_html(html);
+
+    assert_eq!(
+        warnings,
+        vec![ParseWarning::MultipleTypes("profile".to_owned())]
+    );
+
+    let Some(OpenGraphType::Article(article)) = open_graph.kind else {
+        panic!("expected article");
+    };
+
+    assert_eq!(article.section.as_deref(), Some("Nothing"));
+}
+
+#[test]
+fn test_from_html_namespaces() {
+    let html = r#"<html prefix="og: https://ogp.me/ns# a: http://ogp.me/ns/article# myns: https://myns.example.com/ns#"><head>
+        <meta property="og:type" content="article">
+        <meta property="a:section" content="Nothing">
+        <meta property="myns:foo" content="bar">
+    </head></html>"#;
+
+    let Parsed {
+        open_graph,
+        namespaces,
+        ..
+    } = OpenGraph::from_html(html);
+
+    assert_eq!(
+        namespaces,
+        vec![
+            ("og".to_owned(), "https://ogp.me/ns#".to_owned()),
+            ("a".to_owned(), "http://ogp.me/ns/article#".to_owned()),
+            ("myns".to_owned(), "https://myns.example.com/ns#".to_owned()),
+        ]
+    );
+
+    assert_eq!(
+        open_graph.extra,
+        vec![("myns:foo".to_owned(), "bar".to_owned())]
+    );
+
+    let Some(OpenGraphType::Article(article)) = open_graph.kind else {
+        panic!("expected article");
+    };
+
+    assert_eq!(article.section.as_deref(), Some("Nothing"));
+}
+
+#[test]
+fn test_from_html_unknown_namespace_roundtrip() {
+    let html = r#"<html prefix="og: https://ogp.me/ns# restaurant: https://ogp.me/ns/restaurant#"><head>
+        <meta property="og:title" content="chicken house">
+        <meta property="restaurant:price_rating" content="2">
+    </head></html>"#;
+
+    let og = OpenGraph::from_html(html).open_graph;
+
+    assert_eq!(
+        og.extra,
+        vec![("restaurant:price_rating".to_owned(), "2".to_owned())]
+    );
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert_eq!(
+        html,
+        r#"<html prefix="og: https://ogp.me/ns# restaurant: https://ogp.me/ns/restaurant#"><head><meta property="og:title" content="chicken house"/><meta property="restaurant:price_rating" content="2"/><meta charset="utf-8"/></head></html>"#
+    );
+}
+
+#[test]
+fn test_from_html_escaped_roundtrip() {
+    let html = r#"<html><head><meta property="og:title" content="say &quot;hi&quot; &lt;b&gt; &amp; bye"></head></html>"#;
+
+    let og = OpenGraph::from_html(html).open_graph;
+
+    assert_eq!(og.title.as_deref(), Some(r#"say "hi" <b> & bye"#));
+
+    let html = og.to_document();
+
+    println!("{html}");
+
+    assert!(html
+        .contains(r#"<meta property="og:title" content="say &quot;hi&quot; &lt;b> &amp; bye"/>"#));
+    assert!(html.contains(r#"<title>say "hi" &lt;b> &amp; bye</title>"#));
+
+    let og = OpenGraph::from_html(&html).open_graph;
+
+    assert_eq!(og.title.as_deref(), Some(r#"say "hi" <b> & bye"#));
+}
+
+#[test]
+fn test_from_html_kind_properties_before_type() {
+    let html = r#"<html prefix="og: https://ogp.me/ns# a: https://ogp.me/ns/article#"><head>
+        <meta property="a:section" content="Nothing">
+        <meta property="article:tag" content="chicken">
+        <meta property="og:type" content="article">
+    </head></html>"#;
+
+    let parsed = OpenGraph::from_html(html);
+
+    let Some(OpenGraphType::Article(article)) = parsed.open_graph.kind else {
+        panic!("expected article");
+    };
+
+    assert_eq!(article.section.as_deref(), Some("Nothing"));
+    assert_eq!(article.tag[0].name, "chicken");
+    assert!(parsed.open_graph.extra.is_empty());
+    assert!(parsed.warnings.is_empty());
+}
+
+#[test]
+fn test_from_html_kind_properties_custom_type() {
+    let html = r#"<html prefix="og: https://ogp.me/ns# article: https://ogp.me/ns/article#"><head>
+        <meta property="og:type" content="blogpost">
+        <meta property="article:section" content="Nothing">
+    </head></html>"#;
+
+    let parsed = OpenGraph::from_html(html);
+
+    let Some(OpenGraphType::Article(article)) = parsed.open_graph.kind else {
+        panic!("expected article");
+    };
+
+    assert_eq!(article.section.as_deref(), Some("Nothing"));
+    assert!(parsed.open_graph.extra.is_empty());
+    assert_eq!(
+        parsed.warnings,
+        vec![ParseWarning::UnknownType("blogpost".to_owned())]
+    );
+}
+
+#[test]
+fn test_from_html_image_secure_url() {
+    let og = OpenGraph {
+        images: vec![Image::new("http://og.example.com/chicken.png")
+            .secure_url("https://og.example.com/chicken.png")
+            .size(1200, 630)],
+        ..Default::default()
+    };
+
+    let html = og.to_html();
+    let parsed = OpenGraph::from_html(&html).open_graph;
+
+    assert_eq!(parsed.images.len(), 1);
+    assert_eq!(parsed.images[0].url, "http://og.example.com/chicken.png");
+    assert_eq!(
+        parsed.images[0].secure_url.as_deref(),
+        Some("https://og.example.com/chicken.png")
+    );
+    assert_eq!(parsed.to_html(), html);
+
+    let html = r#"<html><head>
+        <meta property="og:image:secure_url" content="https://og.example.com/chicken.png">
+        <meta property="og:image" content="http://og.example.com/chicken.png">
+    </head></html>"#;
+
+    let parsed = OpenGraph::from_html(html).open_graph;
+
+    assert_eq!(parsed.images.len(), 1);
+    assert_eq!(parsed.images[0].url, "http://og.example.com/chicken.png");
+    assert_eq!(
+        parsed.images[0].secure_url.as_deref(),
+        Some("https://og.example.com/chicken.png")
+    );
+}
+
+#[test]
+fn test_from_link_headers() {
+    let og = OpenGraph::from_link_headers(&[
+        r#"<https://og.example.com/chicken>; rel="canonical""#.to_owned(),
+        r#"<https://og.example.com/favicon.ico>; rel="shortcut icon", <https://og.example.com/chicken.png>; rel=image_src"#.to_owned(),
+    ]);
+
+    assert_eq!(og.url.as_deref(), Some("https://og.example.com/chicken"));
+    assert_eq!(
+        og.image.as_deref(),
+        Some("https://og.example.com/chicken.png")
+    );
+    assert_eq!(
+        og.default_image.as_deref(),
+        Some("https://og.example.com/favicon.ico")
+    );
+
+    let html = og.to_html();
+
+    println!("{html}");
+
+    assert!(html.contains(r#"<meta property="og:url" content="https://og.example.com/chicken"/>"#));
+}
@@ -1,4 +1,8 @@
-use crate::{as_ref, open_graph_nodes_opt, Node};
+use std::str::FromStr;
+
+use crate::{as_ref, error::Error, open_graph_nodes_opt, Node, OgNodes};
+
+pub(crate) const NAMESPACE: (&str, &str) = ("profile", "https://ogp.me/ns/profile#");
 
 #[derive(Debug, Clone, Copy)]
 pub enum Gender {
@@ -15,6 +19,18 @@ impl AsRef<str> for Gender {
     }
 }
 
+impl FromStr for Gender {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "male" => Ok(Gender::Male),
+            "female" => Ok(Gender::Female),
+            _ => Err(Error::UnknownGender(s.to_owned())),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Profile {
     /// A name normally given to an individual by a parent or self-chosen.
@@ -28,18 +44,42 @@ pub struct Profile {
 
     /// Their gender.
     pub gender: Option<Gender>,
+
+    /// Their gender as a free-form string. Takes precedence over `gender`.
+    pub gender_raw: Option<String>,
 }
 
 impl Profile {
-    pub(crate) fn to_nodes(&self) -> Vec<Node> {
+    /// Splits a display name on its last space into `first_name` and `last_name`.
+    ///
+    /// A single word is the `first_name` only.
+    pub fn from_full_name(name: &str) -> Profile {
+        let name = name.trim();
+
+        let (first_name, last_name) = match name.rsplit_once(char::is_whitespace) {
+            Some((first_name, last_name)) => (first_name.trim_end(), Some(last_name)),
+            None => (name, None),
+        };
+
+        Profile {
+            first_name: (!first_name.is_empty()).then(|| first_name.to_owned()),
+            last_name: last_name.map(str::to_owned),
+            ..Default::default()
+        }
+    }
+}
+
+impl OgNodes for Profile {
+    fn to_nodes(&self) -> Vec<Node<'_>> {
         let Profile {
             first_name,
             last_name,
             username,
             gender,
+            gender_raw,
         } = self;
 
-        let gender = as_ref(gender);
+        let gender = gender_raw.as_deref().or(as_ref(gender));
 
         open_graph_nodes_opt![
             ("profile:first_name", first_name),
@@ -48,4 +88,21 @@ impl Profile {
             ("profile:gender", gender)
         ]
     }
+
+    fn namespace(&self) -> Option<(&'static str, &'static str)> {
+        Some(NAMESPACE)
+    }
+}
+
+#[test]
+fn test_from_full_name() {
+    let profile = Profile::from_full_name("Lee TaeWoo");
+
+    assert_eq!(profile.first_name.as_deref(), Some("Lee"));
+    assert_eq!(profile.last_name.as_deref(), Some("TaeWoo"));
+
+    let profile = Profile::from_full_name("Cher");
+
+    assert_eq!(profile.first_name.as_deref(), Some("Cher"));
+    assert_eq!(profile.last_name, None);
 }
@@ -0,0 +1,208 @@
+use crate::{append_opt, as_ref, meta_name, Node, OpenGraph, OpenGraphType};
+
+/// https://developer.x.com/en/docs/twitter-for-websites/cards/overview/markup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Card {
+    Summary,
+    SummaryLargeImage,
+    App,
+    Player,
+}
+
+impl AsRef<str> for Card {
+    fn as_ref(&self) -> &str {
+        match self {
+            Card::Summary => "summary",
+            Card::SummaryLargeImage => "summary_large_image",
+            Card::App => "app",
+            Card::Player => "player",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TwitterCard {
+    /// The type of the card. `summary` if not set.
+    pub card: Option<Card>,
+
+    /// The @username of the website.
+    pub site: Option<String>,
+
+    /// The @username of the content creator.
+    pub creator: Option<String>,
+
+    pub title: Option<String>,
+
+    pub description: Option<String>,
+
+    /// A URL to a unique image representing the content of the page.
+    pub image: Option<String>,
+
+    /// A description of the image.
+    pub image_alt: Option<String>,
+}
+
+impl TwitterCard {
+    /// Fills the unset fields from the corresponding open graph properties, e.g. `title` from `og:title`.
+    ///
+    /// `creator` comes from the first `article:author` if it's an @handle or a twitter url.
+    pub fn with_fallback(&self, og: &OpenGraph) -> TwitterCard {
+        let image = og
+            .images
+            .iter()
+            .find(|image| !image.url.is_empty())
+            .filter(|_| og.image.is_none());
+
+        TwitterCard {
+            card: self.card,
+            site: self.site.clone(),
+            creator: self.creator.clone().or_else(|| match &og.kind {
+                Some(OpenGraphType::Article(article)) => article
+                    .author
+                    .first()
+                    .and_then(|author| twitter_handle(author.url())),
+                _ => None,
+            }),
+            title: self.title.clone().or_else(|| og.title.clone()),
+            description: self.description.clone().or_else(|| og.description.clone()),
+            image: self
+                .image
+                .clone()
+                .or_else(|| og.image.clone())
+                .or_else(|| image.map(|image| image.url.clone()))
+                .or_else(|| og.default_image.clone()),
+            image_alt: self
+                .image_alt
+                .clone()
+                .or_else(|| image.and_then(|image| image.alt.clone())),
+        }
+    }
+
+    pub(crate) fn to_nodes(&self) -> Vec<Node<'_>> {
+        let TwitterCard {
+            card,
+            site,
+            creator,
+            title,
+            description,
+            image,
+            image_alt,
+        } = self;
+
+        let card = as_ref(card).unwrap_or("summary");
+
+        [
+            ("twitter:site", site),
+            ("twitter:creator", creator),
+            ("twitter:title", title),
+            ("twitter:description", description),
+            ("twitter:image", image),
+            ("twitter:image:alt", image_alt),
+        ]
+        .into_iter()
+        .fold(vec![meta_name("twitter:card", card)], |nodes, (name, x)| {
+            append_opt(nodes, x.as_deref().map(|x| meta_name(name, x)))
+        })
+    }
+}
+
+impl OpenGraph {
+    /// A `summary_large_image` card with the title, the description and the first image of the open graph.
+    pub fn to_twitter_card(&self) -> TwitterCard {
+        TwitterCard {
+            card: Some(Card::SummaryLargeImage),
+            ..Default::default()
+        }
+        .with_fallback(self)
+    }
+}
+
+/// `@handle` from `@handle`, `https://twitter.com/handle` or `https://x.com/handle`.
+fn twitter_handle(author: &str) -> Option<String> {
+    let handle = match author.strip_prefix('@') {
+        Some(handle) => handle.to_owned(),
+        None => {
+            let url = url::Url::parse(author).ok()?;
+
+            if !matches!(
+                url.host_str()?.trim_start_matches("www."),
+                "twitter.com" | "x.com"
+            ) {
+                return None;
+            }
+
+            let mut segments = url.path_segments()?.filter(|x| !x.is_empty());
+
+            match (segments.next(), segments.next()) {
+                (Some(handle), None) => handle.trim_start_matches('@').to_owned(),
+                _ => return None,
+            }
+        }
+    };
+
+    let valid = !handle.is_empty()
+        && handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    valid.then(|| format!("@{handle}"))
+}
+
+#[test]
+fn test_with_fallback_creator() {
+    use crate::article::Article;
+
+    let og = |author: &str| OpenGraph {
+        kind: Some(OpenGraphType::Article(Article {
+            author: vec![author.into(), "@chicken".into()],
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+    let card = TwitterCard::default();
+
+    let creator = |author| card.with_fallback(&og(author)).creator;
+
+    assert_eq!(creator("@syrflover").as_deref(), Some("@syrflover"));
+    assert_eq!(
+        creator("https://x.com/syrflover").as_deref(),
+        Some("@syrflover")
+    );
+    assert_eq!(
+        creator("https://twitter.com/syrflover/").as_deref(),
+        Some("@syrflover")
+    );
+    assert_eq!(creator("https://og.example.com/@syrflover"), None);
+
+    let card = TwitterCard {
+        creator: "@fry".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        card.with_fallback(&og("@syrflover")).creator.as_deref(),
+        Some("@fry")
+    );
+}
+
+#[test]
+fn test_to_twitter_card() {
+    let og = crate::fixtures::example_article();
+    let og = OpenGraph {
+        description: "a chicken".to_owned().into(),
+        images: vec![crate::image::Image::new(
+            "https://og.example.com/chicken.png",
+        )],
+        ..og
+    };
+
+    let card = og.to_twitter_card();
+
+    assert_eq!(card.card, Some(Card::SummaryLargeImage));
+    assert_eq!(card.title, og.title);
+    assert_eq!(card.description, og.description);
+    assert_eq!(
+        card.image.as_deref(),
+        Some("https://og.example.com/chicken.png")
+    );
+}
@@ -0,0 +1,899 @@
+use std::fmt;
+
+use url::Url;
+
+use crate::{
+    article, book, book::is_isbn, mime::mime_from_extension, music, profile, video, OpenGraph,
+    OpenGraphType, RenderOptions,
+};
+
+/// https://www.w3.org/TR/referrer-policy/#referrer-policies
+const REFERRER_POLICIES: &[&str] = &[
+    "no-referrer",
+    "no-referrer-when-downgrade",
+    "same-origin",
+    "origin",
+    "strict-origin",
+    "origin-when-cross-origin",
+    "strict-origin-when-cross-origin",
+    "unsafe-url",
+];
+
+/// https://developers.google.com/search/docs/crawling-indexing/robots-meta-tag#directives
+const ROBOTS_DIRECTIVES: &[&str] = &[
+    "all",
+    "index",
+    "noindex",
+    "follow",
+    "nofollow",
+    "none",
+    "noarchive",
+    "nocache",
+    "nosnippet",
+    "noimageindex",
+    "notranslate",
+    "indexifembedded",
+    "max-snippet",
+    "max-image-preview",
+    "max-video-preview",
+    "unavailable_after",
+];
+
+/// https://www.w3.org/TR/css-color-4/#named-colors
+const NAMED_COLORS: &[&str] = &[
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "transparent",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// `referrer_policy` is not one of the referrer policy tokens.
+    InvalidReferrerPolicy(String),
+
+    /// The field has a relative url, but crawlers require absolute urls.
+    RelativeUrl(&'static str),
+
+    /// An article time is after a time that should follow it, e.g. `modified_time` before `published_time`.
+    TimeOrder {
+        earlier: &'static str,
+        later: &'static str,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidReferrerPolicy(x) => write!(f, "invalid referrer policy: {x}"),
+            ValidationError::RelativeUrl(field) => write!(f, "relative url in {field}"),
+            ValidationError::TimeOrder { earlier, later } => {
+                write!(f, "{earlier} is after {later}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Problems that don't make the object invalid, but may be mistakes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWarning {
+    /// `book:isbn` has neither the shape of an ISBN-10 nor an ISBN-13.
+    InvalidIsbn(String),
+
+    /// There is no image, which some platforms, e.g. Discord, require to show a preview.
+    MissingImage,
+
+    /// There is no image but `default_image`, which is emitted instead.
+    FallbackImage(String),
+
+    /// `image_width` or `image_height` is set without `image`, so they aren't emitted.
+    ImageDimensionsWithoutImage,
+
+    /// An entry of `images` has an empty url, so it isn't emitted.
+    EmptyImageUrl,
+
+    /// An entry of `images` has no `alt`, which screen readers and some platforms want.
+    ImageMissingAlt(String),
+
+    /// The `mime_type` of an entry of `images` isn't the one of the extension of its url, e.g. `image/jpeg` for a `.png`.
+    ImageTypeMismatch { url: String, mime_type: String },
+
+    /// `theme_color` or `theme_color_dark` is neither a hex color, an `rgb()`-like function nor a named color.
+    InvalidThemeColor(String),
+
+    /// An `extra` property belongs to another built-in type than `kind`, e.g. `article:section` on a profile.
+    ConflictingFields(String),
+
+    /// A directive of `robots` that crawlers don't know, e.g. a typo of `nofollow`.
+    UnknownRobotsDirective(String),
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationWarning::InvalidIsbn(x) => write!(f, "invalid isbn: {x}"),
+            ValidationWarning::MissingImage => write!(f, "missing image"),
+            ValidationWarning::FallbackImage(x) => write!(f, "using fallback image: {x}"),
+            ValidationWarning::ImageDimensionsWithoutImage => {
+                write!(f, "image dimensions without image")
+            }
+            ValidationWarning::EmptyImageUrl => write!(f, "image with empty url"),
+            ValidationWarning::ImageMissingAlt(x) => write!(f, "image without alt: {x}"),
+            ValidationWarning::ImageTypeMismatch { url, mime_type } => {
+                write!(f, "image type {mime_type} mismatches the url: {url}")
+            }
+            ValidationWarning::InvalidThemeColor(x) => write!(f, "invalid theme color: {x}"),
+            ValidationWarning::ConflictingFields(x) => write!(f, "{x} conflicts with og:type"),
+            ValidationWarning::UnknownRobotsDirective(x) => {
+                write!(f, "unknown robots directive: {x}")
+            }
+        }
+    }
+}
+
+/// Inconsistencies that are possible after parsing messy html.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lint {
+    /// An `og:image:url` in `extra` differs from the `og:image`.
+    ImageUrlMismatch { image: String, image_url: String },
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::ImageUrlMismatch { image, image_url } => {
+                write!(f, "og:image:url {image_url} differs from og:image {image}")
+            }
+        }
+    }
+}
+
+impl OpenGraph {
+    pub fn lint(&self) -> Vec<Lint> {
+        let image = self
+            .image
+            .as_deref()
+            .or_else(|| self.images.first().map(|image| image.url.as_str()));
+
+        let Some(image) = image else {
+            return Vec::new();
+        };
+
+        self.extra
+            .iter()
+            .filter(|(property, content)| property == "og:image:url" && content != image)
+            .map(|(_, image_url)| Lint::ImageUrlMismatch {
+                image: image.to_owned(),
+                image_url: image_url.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the warnings if the object is valid.
+    pub fn validate(&self) -> Result<Vec<ValidationWarning>, ValidationError> {
+        self.validate_with_options(&RenderOptions::default())
+    }
+
+    /// Like [`OpenGraph::validate`], with the warnings specific to the options, e.g. `discord_embed`.
+    pub fn validate_with_options(
+        &self,
+        options: &RenderOptions,
+    ) -> Result<Vec<ValidationWarning>, ValidationError> {
+        let mut warnings = Vec::new();
+
+        let has_image = self.image.is_some()
+            || self.default_image.is_some()
+            || self.images.iter().any(|image| !image.url.is_empty());
+
+        if options.discord_embed && !has_image {
+            warnings.push(ValidationWarning::MissingImage);
+        }
+
+        if let Some(default_image) = self.default_image.as_ref().filter(|_| {
+            self.image.is_none() && self.images.iter().all(|image| image.url.is_empty())
+        }) {
+            warnings.push(ValidationWarning::FallbackImage(default_image.clone()));
+        }
+
+        if let Some(OpenGraphType::Article(article)) = &self.kind {
+            let times = [
+                ("published_time", article.published_time),
+                ("modified_time", article.modified_time),
+                ("expiration_time", article.expiration_time),
+            ];
+
+            for (i, (earlier, x)) in times.iter().enumerate() {
+                for (later, y) in &times[i + 1..] {
+                    if let (Some(x), Some(y)) = (x, y) {
+                        if x > y {
+                            return Err(ValidationError::TimeOrder { earlier, later });
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(referrer_policy) = self.referrer_policy.as_deref() {
+            if !REFERRER_POLICIES.contains(&referrer_policy) {
+                return Err(ValidationError::InvalidReferrerPolicy(
+                    referrer_policy.to_owned(),
+                ));
+            }
+        }
+
+        if self.image.is_none() && (self.image_width.is_some() || self.image_height.is_some()) {
+            warnings.push(ValidationWarning::ImageDimensionsWithoutImage);
+        }
+
+        for theme_color in [&self.theme_color, &self.theme_color_dark]
+            .into_iter()
+            .flatten()
+            .filter(|x| !is_color(x))
+        {
+            warnings.push(ValidationWarning::InvalidThemeColor(theme_color.clone()));
+        }
+
+        if let Some(robots) = self.robots.as_deref() {
+            warnings.extend(
+                robots
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|directive| {
+                        let name = directive.split(':').next().unwrap_or_default();
+
+                        !ROBOTS_DIRECTIVES
+                            .iter()
+                            .any(|x| x.eq_ignore_ascii_case(name.trim()))
+                    })
+                    .map(|directive| {
+                        ValidationWarning::UnknownRobotsDirective(directive.to_owned())
+                    }),
+            );
+        }
+
+        if self.images.iter().any(|image| image.url.is_empty()) {
+            warnings.push(ValidationWarning::EmptyImageUrl);
+        }
+
+        warnings.extend(
+            self.images
+                .iter()
+                .filter(|image| !image.url.is_empty() && image.alt.is_none())
+                .map(|image| ValidationWarning::ImageMissingAlt(image.url.clone())),
+        );
+
+        warnings.extend(self.images.iter().filter_map(|image| {
+            let mime_type = image.mime_type.as_ref()?;
+            let expected = mime_from_extension(&image.url)?;
+
+            (!mime_type.eq_ignore_ascii_case(expected)).then(|| {
+                ValidationWarning::ImageTypeMismatch {
+                    url: image.url.clone(),
+                    mime_type: mime_type.clone(),
+                }
+            })
+        }));
+
+        if let Some(kind) = self.kind.as_ref().filter(|kind| {
+            !matches!(
+                kind,
+                OpenGraphType::Custom { .. } | OpenGraphType::Extension { .. }
+            )
+        }) {
+            let own_prefix = kind.namespace().map(|(prefix, _)| prefix);
+
+            let conflicts = self.extra.iter().filter(|(property, _)| {
+                [
+                    article::NAMESPACE,
+                    book::NAMESPACE,
+                    music::NAMESPACE,
+                    profile::NAMESPACE,
+                    video::NAMESPACE,
+                ]
+                .into_iter()
+                .map(|(prefix, _)| prefix)
+                .filter(|prefix| Some(*prefix) != own_prefix)
+                .any(|prefix| {
+                    property
+                        .strip_prefix(prefix)
+                        .is_some_and(|x| x.starts_with(':'))
+                })
+            });
+
+            warnings.extend(
+                conflicts
+                    .map(|(property, _)| ValidationWarning::ConflictingFields(property.clone())),
+            );
+        }
+
+        if let Some(OpenGraphType::Book(book)) = &self.kind {
+            if let Some(isbn) = book.isbn.as_deref().filter(|x| !is_isbn(x)) {
+                warnings.push(ValidationWarning::InvalidIsbn(isbn.to_owned()));
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    /// How many of the recommended properties, title, type, url, image, description, site_name and locale, are set, from 0.0 to 1.0.
+    pub fn completeness_score(&self) -> f32 {
+        let recommended = [
+            self.title.is_some(),
+            self.kind.is_some() || self.profile.is_some(),
+            self.url.is_some(),
+            self.image.is_some()
+                || self.images.iter().any(|image| !image.url.is_empty())
+                || self.default_image.is_some(),
+            self.description.is_some(),
+            self.site_name.is_some(),
+            self.locale.is_some(),
+        ];
+
+        let populated = recommended.iter().filter(|x| **x).count();
+
+        populated as f32 / recommended.len() as f32
+    }
+
+    pub fn validate_urls(&self) -> Vec<ValidationError> {
+        let OpenGraph {
+            url,
+            image,
+            default_image,
+            images,
+            audio,
+            video,
+            see_also,
+            breadcrumbs,
+            kind,
+            ..
+        } = self;
+
+        // the same urls as `strip_query_params`
+        let kind_urls: Vec<(&'static str, &str)> = match kind {
+            Some(OpenGraphType::Article(article)) => article
+                .author
+                .iter()
+                .map(|x| ("author", x.url()))
+                .chain(
+                    article
+                        .tag
+                        .iter()
+                        .filter_map(|x| Some(("tag", x.url.as_deref()?))),
+                )
+                .collect(),
+            Some(OpenGraphType::Book(book)) => {
+                book.author.iter().map(|x| ("author", x.as_str())).collect()
+            }
+            Some(OpenGraphType::MusicPlaylist(playlist)) => playlist
+                .song
+                .iter()
+                .map(|x| ("song", x.as_str()))
+                .chain(playlist.creator.iter().map(|x| ("creator", x.as_str())))
+                .collect(),
+            Some(OpenGraphType::MusicRadioStation(radio_station)) => radio_station
+                .creator
+                .iter()
+                .map(|x| ("creator", x.as_str()))
+                .collect(),
+            Some(OpenGraphType::VideoMovie(video) | OpenGraphType::VideoOther(video)) => video
+                .actor
+                .iter()
+                .map(|x| ("actor", x.as_str()))
+                .chain(video.director.iter().map(|x| ("director", x.as_str())))
+                .chain(video.writer.iter().map(|x| ("writer", x.as_str())))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let urls = [
+            ("url", url),
+            ("image", image),
+            ("default_image", default_image),
+        ]
+        .into_iter()
+        .filter_map(|(field, x)| Some((field, x.as_deref()?)))
+        .chain(images.iter().flat_map(|x| {
+            [Some(x.url.as_str()), x.secure_url.as_deref()]
+                .into_iter()
+                .flatten()
+                .map(|x| ("image", x))
+        }))
+        .chain(audio.iter().flat_map(|x| {
+            [Some(x.url.as_str()), x.secure_url.as_deref()]
+                .into_iter()
+                .flatten()
+                .map(|x| ("audio", x))
+        }))
+        .chain(video.iter().flat_map(|x| {
+            [Some(x.url.as_str()), x.secure_url.as_deref()]
+                .into_iter()
+                .flatten()
+                .map(|x| ("video", x))
+                .chain(x.actor.iter().map(|x| ("actor", x.as_str())))
+        }))
+        .chain(see_also.iter().map(|x| ("see_also", x.as_str())))
+        .chain(breadcrumbs.iter().map(|(_, x)| ("breadcrumbs", x.as_str())))
+        .chain(kind_urls);
+
+        urls.filter(|(_, x)| Url::parse(x).is_err())
+            .map(|(field, _)| ValidationError::RelativeUrl(field))
+            .collect()
+    }
+}
+
+/// A basic check of the shape, not of the arguments of the functions.
+fn is_color(x: &str) -> bool {
+    let x = x.trim().to_ascii_lowercase();
+
+    if let Some(hex) = x.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    let function = [
+        "rgb(", "rgba(", "hsl(", "hsla(", "hwb(", "lab(", "lch(", "oklab(", "oklch(",
+    ]
+    .iter()
+    .any(|f| x.starts_with(f));
+
+    if function {
+        return x.ends_with(')');
+    }
+
+    NAMED_COLORS.contains(&x.as_str())
+}
+
+#[test]
+fn test_validate_urls() {
+    let og = OpenGraph {
+        url: "https://og.example.com/".to_owned().into(),
+        image: "/images/chicken.png".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og.validate_urls(),
+        vec![ValidationError::RelativeUrl("image")]
+    );
+
+    let og = OpenGraph {
+        url: "https://og.example.com/".to_owned().into(),
+        see_also: vec!["/fry".to_owned()],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og.validate_urls(),
+        vec![ValidationError::RelativeUrl("see_also")]
+    );
+
+    let og = OpenGraph {
+        url: "https://og.example.com/".to_owned().into(),
+        default_image: "/images/default.png".to_owned().into(),
+        images: vec![crate::image::Image {
+            url: "http://og.example.com/chicken.png".to_owned(),
+            secure_url: "//og.example.com/chicken.png".to_owned().into(),
+            ..Default::default()
+        }],
+        breadcrumbs: vec![("Recipes".to_owned(), "/recipes".to_owned())],
+        kind: OpenGraphType::Article(crate::article::Article {
+            tag: vec![crate::article::Tag {
+                name: "chicken".to_owned(),
+                url: "/tags/chicken".to_owned().into(),
+            }],
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og.validate_urls(),
+        vec![
+            ValidationError::RelativeUrl("default_image"),
+            ValidationError::RelativeUrl("image"),
+            ValidationError::RelativeUrl("breadcrumbs"),
+            ValidationError::RelativeUrl("tag"),
+        ]
+    );
+
+    let og = OpenGraph {
+        url: "https://og.example.com/".to_owned().into(),
+        ..Default::default()
+    };
+
+    assert!(og.validate_urls().is_empty());
+}
+
+#[test]
+fn test_validate_empty_image_url() {
+    use crate::image::Image;
+
+    let og = OpenGraph {
+        images: vec![
+            Image::new("https://og.example.com/chicken.png").alt("a fried chicken"),
+            Image::new(""),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(og.validate(), Ok(vec![ValidationWarning::EmptyImageUrl]));
+}
+
+#[test]
+fn test_validate_conflicting_fields() {
+    let og = OpenGraph {
+        extra: vec![
+            ("article:section".to_owned(), "Nothing".to_owned()),
+            ("profile:username".to_owned(), "chicken".to_owned()),
+        ],
+        ..crate::fixtures::example_profile()
+    };
+
+    assert_eq!(
+        og.validate(),
+        Ok(vec![ValidationWarning::ConflictingFields(
+            "article:section".to_owned()
+        )])
+    );
+}
+
+#[test]
+fn test_lint_image_url_mismatch() {
+    let og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        extra: vec![(
+            "og:image:url".to_owned(),
+            "https://og.example.com/fry.png".to_owned(),
+        )],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og.lint(),
+        vec![Lint::ImageUrlMismatch {
+            image: "https://og.example.com/chicken.png".to_owned(),
+            image_url: "https://og.example.com/fry.png".to_owned(),
+        }]
+    );
+
+    let og = OpenGraph {
+        extra: vec![(
+            "og:image:url".to_owned(),
+            "https://og.example.com/chicken.png".to_owned(),
+        )],
+        ..og
+    };
+
+    assert!(og.lint().is_empty());
+}
+
+#[test]
+fn test_lint_image_url_mismatch_from_html() {
+    let html = r#"<html><head>
+        <meta property="og:image" content="https://og.example.com/chicken.png">
+        <meta property="og:image:url" content="https://og.example.com/fry.png">
+    </head></html>"#;
+
+    let og = OpenGraph::from_html(html).open_graph;
+
+    assert_eq!(og.images.len(), 1);
+    assert_eq!(
+        og.lint(),
+        vec![Lint::ImageUrlMismatch {
+            image: "https://og.example.com/chicken.png".to_owned(),
+            image_url: "https://og.example.com/fry.png".to_owned(),
+        }]
+    );
+
+    let html = html.replace("fry.png", "chicken.png");
+
+    let og = OpenGraph::from_html(&html).open_graph;
+
+    assert_eq!(og.images.len(), 1);
+    assert!(og.lint().is_empty());
+}
+
+#[test]
+fn test_validate_image_missing_alt() {
+    use crate::image::Image;
+
+    let og = |image: Image| OpenGraph {
+        images: vec![image],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og(Image::new("https://og.example.com/chicken.png")).validate(),
+        Ok(vec![ValidationWarning::ImageMissingAlt(
+            "https://og.example.com/chicken.png".to_owned()
+        )])
+    );
+    assert_eq!(
+        og(Image::new("https://og.example.com/chicken.png").alt("a fried chicken")).validate(),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn test_validate_theme_color() {
+    let og = |theme_color: &str| OpenGraph {
+        theme_color: theme_color.to_owned().into(),
+        ..Default::default()
+    };
+
+    assert_eq!(og("#4285f4").validate(), Ok(vec![]));
+    assert_eq!(og("blue").validate(), Ok(vec![]));
+    assert_eq!(og("rgb(66, 133, 244)").validate(), Ok(vec![]));
+    assert_eq!(
+        og("notacolor").validate(),
+        Ok(vec![ValidationWarning::InvalidThemeColor(
+            "notacolor".to_owned()
+        )])
+    );
+}
+
+#[test]
+fn test_validate_discord_embed() {
+    let og = OpenGraph {
+        title: "open graph".to_owned().into(),
+        ..Default::default()
+    };
+
+    let options = RenderOptions {
+        discord_embed: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og.validate_with_options(&options),
+        Ok(vec![ValidationWarning::MissingImage])
+    );
+    assert_eq!(og.validate(), Ok(vec![]));
+
+    let og = OpenGraph {
+        image: "https://og.example.com/chicken.png".to_owned().into(),
+        ..og
+    };
+
+    assert_eq!(og.validate_with_options(&options), Ok(vec![]));
+}
+
+#[test]
+fn test_validate_image_type_mismatch() {
+    use crate::image::Image;
+
+    let og = |mime_type: &str| OpenGraph {
+        images: vec![Image::new("https://og.example.com/chicken.png")
+            .mime_type(mime_type)
+            .alt("a fried chicken")],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og("image/jpeg").validate(),
+        Ok(vec![ValidationWarning::ImageTypeMismatch {
+            url: "https://og.example.com/chicken.png".to_owned(),
+            mime_type: "image/jpeg".to_owned(),
+        }])
+    );
+    assert_eq!(og("image/png").validate(), Ok(vec![]));
+}
+
+#[test]
+fn test_validate_time_order() {
+    let og = |published_time: &str, modified_time: &str| OpenGraph {
+        kind: OpenGraphType::Article(article::Article {
+            published_time: published_time.parse().ok(),
+            modified_time: modified_time.parse().ok(),
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        og("2023-03-12T11:25:33+09:00", "2022-12-19T16:39:57+09:00").validate(),
+        Err(ValidationError::TimeOrder {
+            earlier: "published_time",
+            later: "modified_time",
+        })
+    );
+    assert_eq!(
+        og("2022-12-19T16:39:57+09:00", "2023-03-12T11:25:33+09:00").validate(),
+        Ok(vec![])
+    );
+    assert_eq!(crate::fixtures::example_article().validate(), Ok(vec![]));
+}
+
+#[test]
+fn test_validate_isbn() {
+    use crate::book::Book;
+
+    let book = |isbn: &str| OpenGraph {
+        kind: OpenGraphType::Book(Book {
+            isbn: isbn.to_owned().into(),
+            ..Default::default()
+        })
+        .into(),
+        ..Default::default()
+    };
+
+    assert_eq!(book("978-3-16-148410-0").validate(), Ok(vec![]));
+    assert_eq!(book("0-306-40615-X").validate(), Ok(vec![]));
+    assert_eq!(
+        book("978-3-16").validate(),
+        Ok(vec![ValidationWarning::InvalidIsbn("978-3-16".to_owned())])
+    );
+}
+
+#[test]
+fn test_completeness_score() {
+    let og = OpenGraph {
+        description: "this is open graph".to_owned().into(),
+        site_name: "open graph".to_owned().into(),
+        locale: "en_US".to_owned().into(),
+        ..OpenGraph::website(
+            "open graph",
+            "https://og.example.com/",
+            "https://og.example.com/logo.png",
+        )
+    };
+
+    assert_eq!(og.completeness_score(), 1.0);
+    assert_eq!(OpenGraph::default().completeness_score(), 0.0);
+
+    let og = OpenGraph {
+        image: None,
+        images: vec![crate::image::Image::new("")],
+        ..og
+    };
+
+    assert_eq!(og.completeness_score(), 6.0 / 7.0);
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::{iso8601, merge, open_graph_nodes_opt, open_graph_nodes_vec, Node, OgNodes};
+
+pub(crate) const NAMESPACE: (&str, &str) = ("video", "https://ogp.me/ns/video#");
+
+#[derive(Debug, Clone, Default)]
+pub struct Video {
+    /// A URL to a video file that complements this object.
+    pub url: String,
+
+    /// An alternate url to use if the webpage requires HTTPS.
+    pub secure_url: Option<String>,
+
+    /// A MIME type for this video.
+    pub mime_type: Option<String>,
+
+    /// The number of pixels wide.
+    pub width: Option<u32>,
+
+    /// The number of pixels high.
+    pub height: Option<u32>,
+
+    /// Actors in this video, emitted as `video:actor` for pages that aren't of a video type.
+    pub actor: Vec<String>,
+
+    /// Tag words associated with this video, emitted as `video:tag`.
+    pub tag: Vec<String>,
+}
+
+impl Video {
+    /// Whether the `video:` namespace is needed for `actor` or `tag`.
+    pub(crate) fn has_metadata(&self) -> bool {
+        !self.actor.is_empty() || !self.tag.is_empty()
+    }
+
+    pub(crate) fn to_nodes(&self) -> Vec<Node<'_>> {
+        let Video {
+            url,
+            secure_url,
+            mime_type,
+            width,
+            height,
+            actor,
+            tag,
+        } = self;
+
+        let url = Some(url);
+        let width = width.map(|x| x.to_string());
+        let height = height.map(|x| x.to_string());
+
+        merge(
+            open_graph_nodes_opt![
+                ("og:video", url),
+                ("og:video:secure_url", secure_url),
+                ("og:video:type", mime_type),
+                ("og:video:width", width),
+                ("og:video:height", height),
+            ],
+            open_graph_nodes_vec![("video:actor", actor), ("video:tag", tag)],
+        )
+    }
+}
+
+impl From<String> for Video {
+    fn from(url: String) -> Self {
+        Self {
+            url,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for Video {
+    fn from(url: &str) -> Self {
+        url.to_owned().into()
+    }
+}
+
+/// The properties shared by all video types.
+#[derive(Debug, Clone, Default)]
+pub struct VideoCommon {
+    /// Actors in the video.
+    pub actor: Vec<String>,
+
+    /// Directors of the video.
+    pub director: Vec<String>,
+
+    /// Writers of the video.
+    pub writer: Vec<String>,
+
+    /// The video's length in seconds.
+    pub duration: Option<u32>,
+
+    /// The date the video was released.
+    pub release_date: Option<DateTime<Utc>>,
+
+    /// Tag words associated with this video.
+    pub tag: Vec<String>,
+}
+
+impl OgNodes for VideoCommon {
+    fn to_nodes(&self) -> Vec<Node<'_>> {
+        self.to_nodes_in(None)
+    }
+
+    fn to_nodes_in(&self, timezone: Option<FixedOffset>) -> Vec<Node<'_>> {
+        let VideoCommon {
+            actor,
+            director,
+            writer,
+            duration,
+            release_date,
+            tag,
+        } = self;
+
+        let duration = duration.map(|x| x.to_string());
+
+        iso8601![timezone; release_date];
+
+        merge(
+            open_graph_nodes_vec![
+                ("video:actor", actor),
+                ("video:director", director),
+                ("video:writer", writer),
+            ],
+            merge(
+                open_graph_nodes_opt![
+                    ("video:duration", duration),
+                    ("video:release_date", release_date),
+                ],
+                open_graph_nodes_vec![("video:tag", tag)],
+            ),
+        )
+    }
+
+    fn namespace(&self) -> Option<(&'static str, &'static str)> {
+        Some(NAMESPACE)
+    }
+}